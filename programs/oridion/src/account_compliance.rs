@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::account_land::LandBook;
+use super::*;
+
+// Compliance adds an address to the denylist
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct DenyAddress<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DenyEntry::INIT_SPACE,
+        seeds = [b"deny", address.as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, DenyEntry>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(mut, address = universe.compliance_authority @ OridionError::NotComplianceAuthority)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Compliance removes an address from the denylist
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AllowAddress<'info> {
+    #[account(
+        mut,
+        seeds = [b"deny", address.as_ref()],
+        bump = entry.bump,
+        close = authority
+    )]
+    pub entry: Account<'info, DenyEntry>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(mut, address = universe.compliance_authority @ OridionError::NotComplianceAuthority)]
+    pub authority: Signer<'info>,
+}
+
+// Compliance freezes a pod
+#[derive(Accounts)]
+pub struct FreezePod<'info> {
+    #[account(mut)]
+    pub pod: Account<'info, Pod>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(address = universe.compliance_authority @ OridionError::NotComplianceAuthority)]
+    pub authority: Signer<'info>,
+}
+
+// Releases a frozen pod. Compliance at any time, anyone once the freeze expired.
+#[derive(Accounts)]
+pub struct ReleasePod<'info> {
+    #[account(mut)]
+    pub pod: Account<'info, Pod>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    pub signer: Signer<'info>,
+}
+
+// Compliance refunds a frozen pod to the wallet that funded it
+#[derive(Accounts)]
+pub struct RefundPod<'info> {
+    #[account(mut)]
+    pub pod: Account<'info, Pod>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump
+    )]
+    pub pod_legs: Option<Account<'info, PodLegs>>,
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
+    #[account(mut, address = pod.location @ OridionError::PlanetNotPodLocation)]
+    pub from_planet: Account<'info, Planet>,
+    #[account(mut)]
+    pub source: SystemAccount<'info>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(address = universe.compliance_authority @ OridionError::NotComplianceAuthority)]
+    pub authority: Signer<'info>,
+}
+
+// One PDA per denied address. Launch and land fail while it exists.
+#[account]
+#[derive(InitSpace)]
+pub struct DenyEntry {
+    pub address: Pubkey,
+    pub reason: u8, // Compliance reason code
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+/// Emitted on every denylist change so auditors can replay the list.
+#[event]
+pub struct DenylistUpdated {
+    pub address: Pubkey,
+    pub denied: bool,
+    pub reason: u8,
+    pub at: i64,
+}
+
+#[event]
+pub struct PodFrozen {
+    pub pod: Pubkey,
+    pub reason: u8,
+    pub until: i64,
+}
+
+#[event]
+pub struct PodReleased {
+    pub pod: Pubkey,
+    pub at: i64,
+}
+
+#[event]
+pub struct PodRefunded {
+    pub pod: Pubkey,
+    pub reason: u8,
+    pub lamports: u64,
+    pub at: i64,
+}
@@ -21,4 +21,43 @@ pub struct InitLandBook<'info> {
 pub struct LandBook {
     #[max_len(128)]
     pub tickets: Vec<[u8; 16]>,
+}
+impl LandBook {
+    /// Consumes a ticket. Fails if it is not in the book.
+    pub fn take(&mut self, ticket: &[u8; 16]) -> Result<()> {
+        let Some(i) = self.tickets.iter().position(|t| t == ticket)
+        else { return err!(OridionError::TicketNotFound) };
+        self.tickets.swap_remove(i);
+        Ok(())
+    }
+
+    /// Removes a ticket if present.
+    pub fn discard(&mut self, ticket: &[u8; 16]) {
+        if let Some(i) = self.tickets.iter().position(|t| t == ticket) {
+            self.tickets.swap_remove(i);
+        }
+    }
+
+    /// Delivers the pod at `key` once. `Landed` consumes its ticket and fails without one.
+    /// `Emergency` and `Cancelled` discard its ticket and any armed leg tickets, so
+    /// `settle` can never deliver it again. Shared by `settle`, `user_settle` and `refund_pod`.
+    pub fn land_pod(
+        &mut self,
+        key: &Pubkey,
+        pod: &mut Pod,
+        legs: Option<&PodLegs>,
+        to: PodState,
+        now: i64,
+    ) -> Result<()> {
+        pod.land(to, now)?;
+        if to == PodState::Landed {
+            return self.take(&pod.land_ticket(key));
+        }
+        self.discard(&pod.land_ticket(key));
+        if pod.legs > 0 {
+            let legs = legs.ok_or(OridionError::PodLegsMissing)?;
+            legs.discard_tickets(key, pod, self);
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
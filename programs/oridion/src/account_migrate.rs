@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+use anchor_lang::{Discriminator, Space};
+use super::*;
+use crate::errors::OridionError;
+
+// Manager rewrites an account still in its pre-upgrade (v3) layout.
+// The account grows to the current size; the manager pays the extra rent.
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// CHECK: Program account in a legacy layout. Checked by discriminator and size.
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Pods also move their land ticket to the current format
+#[derive(Accounts)]
+pub struct MigratePod<'info> {
+    /// CHECK: Pod in the legacy layout. Checked by discriminator and size.
+    #[account(mut, owner = crate::ID)]
+    pub pod: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyUniverse {
+    pub account_type: u8,
+    pub locked: u8,
+    pub bump: u8,
+    pub created: i64,
+    pub last_updated: i64,
+    pub fee: u64, // Flat fee for every mode
+    pub increment: u64, // Delay mode fee per hop
+}
+
+impl LegacyUniverse {
+    /// Keeps the fee schedule: the flat fee for every mode plus the per hop increment in Delay mode.
+    pub fn upgrade(&self) -> Universe {
+        Universe {
+            account_type: self.account_type,
+            locked: self.locked,
+            bump: self.bump,
+            created: self.created,
+            last_updated: self.last_updated,
+            fees: Universe::flat_fees(self.fee, self.increment),
+            delays: Universe::default_delays(),
+            ..Universe::default()
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyPod {
+    pub account_type: u8,
+    pub version: u8,
+    pub mode: u8,
+    pub next_process: u8, // 0 hop, 1 land
+    pub last_process: u8, // 0 launch, 1 hop
+    pub is_in_transit: u8,
+    pub id: u16,
+    pub hops: u16,
+    pub delay: u32,
+    pub next_process_at: i64,
+    pub land_at: i64,
+    pub created_at: i64,
+    pub last_process_at: i64,
+    pub lamports: u64,
+    pub location: Pubkey,
+    pub destination: [u8; 32],
+    pub passcode_hash: [u8; 32],
+    pub authority: [u8; 32],
+}
+
+impl LegacyPod {
+    /// Land ticket in the legacy format, not bound to the pod address.
+    pub fn land_ticket(&self) -> [u8; 16] {
+        let digest = hashv(&[
+            b"ORIDION_LAND_V1",
+            &self.id.to_le_bytes(),
+            &self.lamports.to_le_bytes(),
+            &self.created_at.to_le_bytes(),
+        ]).to_bytes();
+        let mut ticket = [0u8; 16];
+        ticket.copy_from_slice(&digest[..16]);
+        ticket
+    }
+
+    /// Rebuilds the pod state from the legacy process flags. `ticketed` is whether its legacy ticket
+    /// is still in the book. A pod that hopped onto its landing without a ticket was already settled.
+    /// Pods mid star or scatter hop cannot be migrated; finish those hops before the upgrade.
+    /// Legacy pods are not indexed, and their rent returns to the manager as it did before.
+    pub fn upgrade(&self, key: &Pubkey, ticketed: bool) -> Result<Pod> {
+        require!(self.is_in_transit == 0, OridionError::InTransit);
+        let (state, landed_at) = match (ticketed, self.next_process, self.last_process) {
+            (true, _, _) => (PodState::ReadyToLand, 0),
+            (false, 1, 1) => (PodState::Landed, self.last_process_at.max(1)),
+            (false, _, 0) => (PodState::Launched, 0),
+            (false, _, _) => (PodState::Hopping, 0),
+        };
+        Ok(Pod {
+            account_type: self.account_type,
+            version: self.version,
+            mode: self.mode,
+            state,
+            hops: self.hops,
+            delay: self.delay,
+            id: self.id as u64,
+            next_process_at: self.next_process_at,
+            land_at: self.land_at,
+            created_at: self.created_at,
+            last_process_at: self.last_process_at,
+            landed_at,
+            lamports: self.lamports,
+            location: self.location,
+            destination: self.destination,
+            passcode_hash: self.passcode_hash,
+            authority: self.authority,
+            rent_payer: Pod::source_hash(&MANAGER_PUBKEY, key),
+            ..Pod::default()
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyPodMeta {
+    #[max_len(50)]
+    pub ids: Vec<u16>,
+    pub created_at: i64,
+}
+
+impl LegacyPodMeta {
+    /// Legacy pods were seeded by a 2-byte id, so their addresses never collide with new ids
+    /// and the index starts empty.
+    pub fn upgrade(&self) -> PodMeta {
+        PodMeta { created_at: self.created_at, ..PodMeta::default() }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyTreasury {
+    pub bump: u8,
+    pub authority: Pubkey,
+}
+
+impl LegacyTreasury {
+    pub fn upgrade(&self) -> Treasury {
+        Treasury { bump: self.bump, authority: self.authority, ..Treasury::default() }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyStar {
+    pub amount: u64,
+    pub manager: Pubkey,
+}
+
+impl LegacyStar {
+    /// A legacy star joins the registered pool. Stars still holding a hop's lamports cannot.
+    pub fn upgrade(&self, bump: u8) -> Result<Star> {
+        require!(self.amount == 0, OridionError::StarInUse);
+        Ok(Star { amount: 0, held_by: Pubkey::default(), held_at: 0, bump })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyPlanet {
+    pub account_type: u8,
+    pub bump: u8,
+    pub created: i64,
+    pub visits: u64,
+    pub base_lamports: u64,
+    pub locked_at: i64,
+    pub locked_by: Pubkey,
+    #[max_len(10)]
+    pub name: String,
+}
+
+impl LegacyPlanet {
+    pub fn upgrade(&self) -> Planet {
+        Planet {
+            account_type: self.account_type,
+            bump: self.bump,
+            created: self.created,
+            visits: self.visits,
+            base_lamports: self.base_lamports,
+            locked_at: self.locked_at,
+            locked_by: self.locked_by,
+            name: self.name.clone(),
+            ..Planet::default()
+        }
+    }
+}
+
+/// Reads `info` as legacy `L`. The account must carry `T`'s discriminator and exactly the legacy size,
+/// so an account that was already migrated is rejected.
+pub fn read_legacy<L: AnchorDeserialize + Space, T: Discriminator>(info: &AccountInfo) -> Result<L> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() == 8 + L::INIT_SPACE && data.starts_with(T::DISCRIMINATOR),
+        OridionError::NotLegacyAccount
+    );
+    L::deserialize(&mut &data[8..]).map_err(|_| error!(OridionError::NotLegacyAccount))
+}
+
+/// Grows `info` to the current size of `T`, tops up its rent from the manager and writes `account`.
+pub fn write_migrated<'info, T: AccountSerialize + Space>(
+    info: &AccountInfo<'info>,
+    manager: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    account: &T,
+) -> Result<()> {
+    let space = 8 + T::INIT_SPACE;
+    let short = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if short > 0 {
+        invoke(
+            &transfer(manager.key, info.key, short),
+            &[manager.to_account_info(), info.clone(), system_program.to_account_info()],
+        )?;
+    }
+    #[allow(deprecated)]
+    info.realloc(space, true)?;
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
@@ -45,6 +45,8 @@ pub struct LockPlanet<'info> {
     #[account(mut)]
     pub planet: Account<'info, Planet>,
     pub pod: Account<'info, Pod>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub creator: Signer<'info>,
 }
@@ -58,13 +60,20 @@ pub struct ScatterLockPlanets<'info> {
     #[account(mut)]
     pub planet_3: Account<'info, Planet>,
     pub pod: Account<'info, Pod>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(
+        seeds = [b"scatter", pod.key().as_ref()],
+        bump = scatter_meta.bump
+    )]
+    pub scatter_meta: Account<'info, TransitMeta>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub creator: Signer<'info>,
 }
 
 #[account]
 #[repr(C)]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Planet {
     pub account_type: u8,        // 2 = Planet
     pub bump: u8,                // Bump
@@ -75,4 +84,67 @@ pub struct Planet {
     pub locked_by: Pubkey,       // Pod that owns the lock
     #[max_len(10)]
     pub name: String,            // Max 10-char planet ID
-}
\ No newline at end of file
+    pub lock_nonce: u64,         // Bumped on every lock acquisition
+    pub lock_acquired: u64,      // Locks taken
+    pub lock_expired: u64,       // Locks taken over after expiring unreleased
+    pub pre_lock_contended: u64, // lock_node/fan_lock requests refused because another pod held the planet
+}
+
+impl Planet {
+    /// Lock is held and has not expired at `now`. A lock expires once it is
+    /// `expiry` seconds old, the same boundary for every lock check.
+    pub fn lock_live(&self, now: i64, expiry: i64) -> bool {
+        self.locked_at != 0 && now - self.locked_at < expiry
+    }
+
+    /// Locks the planet for `pod` unless another pod holds a live lock.
+    /// Every acquisition gets a new nonce.
+    fn take_lock(&mut self, pod: Pubkey, now: i64, expiry: i64) -> Option<u64> {
+        if self.locked_by != pod && self.lock_live(now, expiry) {
+            return None;
+        }
+        // Lock left behind without a release
+        if self.locked_at != 0 && !self.lock_live(now, expiry) {
+            self.lock_expired = self.lock_expired.saturating_add(1);
+        }
+        self.locked_by = pod;
+        self.locked_at = now;
+        self.lock_nonce = self.lock_nonce.wrapping_add(1);
+        self.lock_acquired = self.lock_acquired.saturating_add(1);
+        Some(self.lock_nonce)
+    }
+
+    /// Pre-lock for `lock_node`/`fan_lock`. A refusal succeeds without the lock,
+    /// so it is counted as contention.
+    pub fn try_lock(&mut self, pod: Pubkey, now: i64, expiry: i64) -> Option<u64> {
+        let nonce = self.take_lock(pod, now, expiry);
+        if nonce.is_none() {
+            self.count_contention();
+        }
+        nonce
+    }
+
+    /// Records a pre-lock refused because another pod held the planet.
+    pub fn count_contention(&mut self) {
+        self.pre_lock_contended = self.pre_lock_contended.saturating_add(1);
+    }
+
+    /// Lock that fails on contention. The failure reverts the instruction, so it is not counted.
+    pub fn acquire_lock(&mut self, pod: Pubkey, now: i64, expiry: i64) -> Result<u64> {
+        self.take_lock(pod, now, expiry).ok_or(OridionError::PlanetStillLocked.into())
+    }
+
+    /// Locks the planet for an instruction moving `pod`'s funds.
+    /// With `nonce` the pod must still hold that exact lock from `lock_node`/`fan_lock`;
+    /// without it the lock is taken here and released by the same instruction.
+    pub fn enter(&mut self, pod: Pubkey, nonce: Option<u64>, now: i64, expiry: i64) -> Result<()> {
+        let Some(nonce) = nonce else {
+            return self.acquire_lock(pod, now, expiry).map(|_| ());
+        };
+        require!(self.locked_at != 0, OridionError::PlanetNotLocked);
+        require_keys_eq!(self.locked_by, pod, OridionError::NotAuthorizedToHop);
+        require!(self.lock_live(now, expiry), OridionError::LockExpired);
+        require!(self.lock_nonce == nonce, OridionError::LockNonceMismatch);
+        Ok(())
+    }
+}
@@ -1,32 +1,181 @@
 use super::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::account_land::LandBook;
+use crate::oridion::PodArgs;
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum PodEntryStatus {
+    #[default]
+    Active,
+    Landed,
+    Closed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct PodEntry {
+    pub id: u64,
+    pub status: PodEntryStatus,
+}
+
+/// Bounded list of a creator's pods. Active and landed entries are never evicted;
+/// slots of closed pods are reused once the list is full.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct PodIndex {
+    #[max_len(MAX_USER_META_PODS)]
+    pub entries: Vec<PodEntry>,
+}
+impl PodIndex {
+    pub fn has_room(&self) -> bool {
+        self.entries.len() < MAX_USER_META_PODS
+            || self.entries.iter().any(|e| e.status == PodEntryStatus::Closed)
+    }
+
+    /// Adds `id` as active. Fails with TooManyPods instead of evicting open pods.
+    pub fn insert(&mut self, id: u64) -> Result<()> {
+        let entry = PodEntry { id, status: PodEntryStatus::Active };
+        if self.entries.len() < MAX_USER_META_PODS {
+            self.entries.push(entry);
+            return Ok(());
+        }
+        let slot = self.entries
+            .iter_mut()
+            .find(|e| e.status == PodEntryStatus::Closed)
+            .ok_or(OridionError::TooManyPods)?;
+        *slot = entry;
+        Ok(())
+    }
+
+    /// No open (active or landed) pods left.
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(|e| e.status == PodEntryStatus::Closed)
+    }
+
+    /// Moves the open entry for `id` to `status`. Returns false if there is none.
+    pub fn set_status(&mut self, id: u64, status: PodEntryStatus) -> bool {
+        match self.entries.iter_mut().find(|e| e.id == id && e.status != PodEntryStatus::Closed) {
+            Some(entry) => {
+                entry.status = status;
+                true
+            }
+            None => false,
+        }
+    }
+}
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct PodMeta {
-    #[max_len(50)]
-    pub ids: Vec<u16>,
+    pub index: PodIndex,
+    pub pages: u16, // Overflow pages opened so far
     pub created_at: i64,
+    // Rolling deposit volume. Bucket `volume_bucket % CREATOR_VOLUME_BUCKETS` is the current one.
+    pub volume_bucket: i64,
+    pub volume: [u64; CREATOR_VOLUME_BUCKETS],
 }
 impl PodMeta {
-    pub fn prune_ids(&mut self) {
-        let len = self.ids.len();
-        if len > MAX_USER_META_PODS {
-            let excess = len - MAX_USER_META_PODS;
-            self.ids.drain(0..excess); // 🧹 remove oldest items
+    /// Nothing left to track: no open pods, no pages and no volume inside the limit window.
+    /// Closing earlier would reset the creator's volume limit. Pod ids come from the universe,
+    /// so a reopened meta never hands out an id twice.
+    pub fn is_empty(&self, now: i64, limits: &DepositLimits) -> bool {
+        self.index.is_empty() && self.pages == 0 && self.volume_in_window(now, limits) == 0
+    }
+
+    /// Deposit volume still inside the rolling window at `now`.
+    pub fn volume_in_window(&self, now: i64, limits: &DepositLimits) -> u64 {
+        if limits.creator_volume == 0 {
+            return 0;
         }
+        let buckets = CREATOR_VOLUME_BUCKETS as i64;
+        let span = (limits.creator_window as i64 / buckets).max(1);
+        let live = buckets - (now / span - self.volume_bucket).clamp(0, buckets);
+        (0..live)
+            .map(|j| self.volume[(self.volume_bucket - j).rem_euclid(buckets) as usize])
+            .sum()
     }
 
-    pub fn remove_id(&mut self, id: u16) {
-        if let Some(pos) = self.ids.iter().position(|x| *x == id) {
-            self.ids.remove(pos);
+    /// Counts a deposit against the creator's rolling volume limit.
+    /// The window is split into buckets; buckets older than the window are cleared first.
+    pub fn add_volume(&mut self, lamports: u64, now: i64, limits: &DepositLimits) -> Result<()> {
+        if limits.creator_volume == 0 {
+            return Ok(());
         }
+        let buckets = CREATOR_VOLUME_BUCKETS as i64;
+        let span = (limits.creator_window as i64 / buckets).max(1);
+        let bucket = now / span;
+        let stale = (bucket - self.volume_bucket).clamp(0, buckets);
+        for i in 1..=stale {
+            self.volume[((self.volume_bucket + i) % buckets) as usize] = 0;
+        }
+        self.volume_bucket = self.volume_bucket.max(bucket);
+
+        let total = self.volume
+            .iter()
+            .try_fold(lamports, |acc, v| acc.checked_add(*v))
+            .ok_or(OridionError::UnusualMathError)?;
+        require!(total <= limits.creator_volume, OridionError::CreatorLimitExceeded);
+        self.volume[(self.volume_bucket % buckets) as usize] += lamports;
+        Ok(())
     }
 }
 
+// Overflow pod index for creators with more than MAX_USER_META_PODS open pods
+#[account]
+#[derive(InitSpace)]
+pub struct PodMetaPage {
+    pub page: u16,
+    pub index: PodIndex,
+    pub bump: u8,
+}
+
+/// Moves `id` to `status` in the creator's pod meta, or in the overflow page holding it.
+/// Indexed pods must find their entry, so the index can never be left with a stale open pod.
+/// Pods without an entry (private, scheduled) ignore whatever is passed.
+pub fn mark_index_entry(
+    indexed: bool,
+    meta: Option<&mut PodIndex>,
+    page: Option<&mut PodIndex>,
+    id: u64,
+    status: PodEntryStatus,
+) -> Result<()> {
+    if !indexed {
+        return Ok(());
+    }
+    let meta = meta.ok_or(OridionError::PodMetaMissing)?;
+    if meta.set_status(id, status) {
+        return Ok(());
+    }
+    let found = page.is_some_and(|page| page.set_status(id, status));
+    require!(found, OridionError::PodEntryNotFound);
+    Ok(())
+}
+
+/// `mark_index_entry` for a pod account. The contexts bind `meta` and `page` to the pod's creator.
+pub fn mark_pod_entry(
+    pod: &Pod,
+    meta: Option<&mut Account<PodMeta>>,
+    page: Option<&mut Account<PodMetaPage>>,
+    status: PodEntryStatus,
+) -> Result<()> {
+    mark_index_entry(
+        pod.indexed,
+        meta.map(|meta| &mut meta.index),
+        page.map(|page| &mut page.index),
+        pod.id,
+        status,
+    )
+}
+
+/// Key of an optional account, default when absent, so optional accounts can seed other accounts.
+/// Seeds derived from the default key never match, so a meta passed without its creator is rejected.
+pub fn key_or_default(account: &Option<UncheckedAccount>) -> Pubkey {
+    account.as_ref().map(|account| account.key()).unwrap_or_default()
+}
+
 
 // Creates a pod account
 #[derive(Accounts)]
-#[instruction(id: u16)]
+#[instruction(args: PodArgs)]
 pub struct CreatePod<'info> {
     #[account(
         init_if_needed,
@@ -36,14 +185,165 @@ pub struct CreatePod<'info> {
         bump
     )]
     pub pod_meta: Account<'info, PodMeta>,
+    // Used once the primary pod meta is full
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", creator.key().as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump
+    )]
+    pub pod_meta_page: Option<Account<'info, PodMetaPage>>,
     #[account(
         init,
         payer = creator,
         space = 8 + Pod::INIT_SPACE,
+        seeds = [b"pod", creator.key().as_ref(), &universe.next_pod_id.to_le_bytes()],
+        bump
+    )]
+    pub pod: Account<'info, Pod>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(mut, seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info,Universe>,
+    #[account(mut)]
+    pub planet: Account<'info,Planet>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    // Registered partner that referred the launch, earns a share of the fee
+    #[account(
+        mut,
+        seeds = [b"partner", referrer.wallet.as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Option<Account<'info, Partner>>,
+    /// CHECK: Denylist PDAs. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", creator.key().as_ref()], bump)]
+    pub creator_deny: UncheckedAccount<'info>,
+    /// CHECK: See `creator_deny`. Sealed destinations are screened at land time.
+    #[account(seeds = [b"deny", args.de1.as_ref(), args.de2.as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
+    pub system_program: Program<'info,System>
+}
+
+
+// Opens the next overflow page of a creator's pod meta
+#[derive(Accounts)]
+pub struct OpenMetaPage<'info> {
+    #[account(
+        mut,
+        seeds = [b"pod_meta", creator.key().as_ref()],
+        bump
+    )]
+    pub pod_meta: Account<'info, PodMeta>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PodMetaPage::INIT_SPACE,
+        seeds = [b"pod_meta_page", creator.key().as_ref(), &pod_meta.pages.to_le_bytes()],
+        bump
+    )]
+    pub pod_meta_page: Account<'info, PodMetaPage>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+
+// Splits a freshly launched pod into delivery legs.
+// Sent by the creator in the same transaction as `launch`.
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct SplitPod<'info> {
+    #[account(
+        mut,
         seeds = [b"pod", creator.key().as_ref(), &id.to_le_bytes()],
         bump
     )]
     pub pod: Account<'info, Pod>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PodLegs::INIT_SPACE,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump
+    )]
+    pub pod_legs: Account<'info, PodLegs>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// A single delivery leg of a split pod
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PodLeg {
+    pub destination: [u8; 32], // Destination wallet address
+    pub lamports: u64, // Lamports delivered by this leg
+    pub land_at: i64, // Landing timestamp
+    pub status: u8, // 0 pending, 1 ticketed, 2 landed
+}
+
+// Delivery legs of a split pod. Seeded by [b"pod_legs", pod].
+#[account]
+#[derive(InitSpace)]
+pub struct PodLegs {
+    #[max_len(MAX_POD_LEGS)]
+    pub legs: Vec<PodLeg>,
+    pub bump: u8,
+}
+impl PodLegs {
+    /// Pushes a land ticket for every pending leg landing within `window` seconds.
+    pub fn arm_due(&mut self, key: &Pubkey, pod: &Pod, book: &mut LandBook, now: i64, window: i64) -> Result<()> {
+        for (index, leg) in self.legs.iter_mut().enumerate() {
+            if leg.status != 0 || leg.land_at.saturating_sub(now) > window {
+                continue;
+            }
+            let tok = leg_token_from(key, pod.id, index as u8, leg.lamports, pod.created_at);
+            if !book.tickets.contains(&tok) {
+                require!(book.tickets.len() < 128, OridionError::LandBookFull);
+                book.tickets.push(tok);
+            }
+            leg.status = 1;
+        }
+        Ok(())
+    }
+
+    pub fn all_landed(&self) -> bool {
+        self.legs.iter().all(|leg| leg.status == 2)
+    }
+
+    /// Removes the tickets of every leg that has not landed yet.
+    pub fn discard_tickets(&self, key: &Pubkey, pod: &Pod, book: &mut LandBook) {
+        for (index, leg) in self.legs.iter().enumerate() {
+            if leg.status == 1 {
+                book.discard(&leg_token_from(key, pod.id, index as u8, leg.lamports, pod.created_at));
+            }
+        }
+    }
+}
+
+
+// Launches a private pod seeded by a commitment instead of the creator.
+// The pod is never indexed. The creator's pod meta is only needed to track volume while
+// creator volume limits are on; otherwise it can be left out and is never created.
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32], args: PodArgs)]
+pub struct CreatePrivatePod<'info> {
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PodMeta::INIT_SPACE,
+        seeds = [b"pod_meta", creator.key().as_ref()],
+        bump
+    )]
+    pub pod_meta: Option<Account<'info, PodMeta>>,
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Pod::INIT_SPACE,
+        seeds = [b"pod_c", commitment.as_ref()],
+        bump
+    )]
+    pub pod: Account<'info, Pod>,
     #[account(mut)]
     pub creator: Signer<'info>,
     #[account(mut)]
@@ -51,57 +351,430 @@ pub struct CreatePod<'info> {
     #[account(mut)]
     pub planet: Account<'info,Planet>,
 
-    /// CHECK: This account is a lamport collector (PDA). It is not deserialized or mutated.
-    ///        We verify its address via seeds and ensure it is program-owned when necessary.
-    ///        Used only to receive lamports — no data validation required.
-    #[account(mut,seeds = [b"treasury"],bump)]
-    pub treasury: AccountInfo<'info>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    // Registered partner that referred the launch, earns a share of the fee
+    #[account(
+        mut,
+        seeds = [b"partner", referrer.wallet.as_ref()],
+        bump = referrer.bump
+    )]
+    pub referrer: Option<Account<'info, Partner>>,
+    /// CHECK: Denylist PDAs. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", creator.key().as_ref()], bump)]
+    pub creator_deny: UncheckedAccount<'info>,
+    /// CHECK: See `creator_deny`. Sealed destinations are screened at land time.
+    #[account(seeds = [b"deny", args.de1.as_ref(), args.de2.as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
     pub system_program: Program<'info,System>
 }
 
 
+// Opaque ciphertext stored alongside a pod (e.g. a sealed destination for the operator)
+#[derive(Accounts)]
+pub struct AttachNote<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PodNote::INIT_SPACE,
+        seeds = [b"pod_note", pod.key().as_ref()],
+        bump
+    )]
+    pub pod_note: Account<'info, PodNote>,
+    pub pod: Account<'info, Pod>,
+    // Only the wallet that funded the pod can attach its note
+    #[account(
+        mut,
+        constraint = Pod::source_hash(&payer.key(), &pod.key()) == pod.source @ OridionError::NotPodCreator
+    )]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PodNote {
+    pub pod: Pubkey,
+    #[max_len(MAX_POD_NOTE_LEN)]
+    pub data: Vec<u8>,
+    pub bump: u8,
+}
+
+
+// Pod metadata encrypted to a viewing key and to the compliance key
+#[derive(Accounts)]
+pub struct Disclose<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PodDisclosure::INIT_SPACE,
+        seeds = [b"pod_disclosure", pod.key().as_ref()],
+        bump
+    )]
+    pub pod_disclosure: Account<'info, PodDisclosure>,
+    pub pod: Account<'info, Pod>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    // Only the wallet that funded the pod can disclose it
+    #[account(
+        mut,
+        constraint = Pod::source_hash(&payer.key(), &pod.key()) == pod.source @ OridionError::NotPodCreator
+    )]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Envelopes hold an ephemeral x25519 key followed by the encrypted `DisclosureMeta`.
+#[account]
+#[derive(InitSpace)]
+pub struct PodDisclosure {
+    pub pod: Pubkey,
+    #[max_len(DISCLOSURE_LEN)]
+    pub viewer: Vec<u8>, // Encrypted to the user's viewing key
+    #[max_len(DISCLOSURE_LEN)]
+    pub compliance: Vec<u8>, // Encrypted to `Universe::compliance_key`
+    pub bump: u8,
+}
+
+/// Plaintext inside a disclosure envelope.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DisclosureMeta {
+    pub creator: Pubkey,
+    pub destination: Pubkey,
+    pub lamports: u64,
+    pub created_at: i64,
+}
+
+
 #[repr(u8)]
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub enum ActivityAction {
+    #[default]
     Launch = 0,
-    Hop = 1,
+    Hop = 1, // Arrived on a planet (planet hop or end of a star/scatter hop)
     Star2 = 2,
     Star3 = 3,
-    Scatter = 4
+    Scatter = 4,
+    Split = 5,
+    Land = 6,
+    LegLand = 7,
+    Emergency = 8,
+    Freeze = 9,
+    Release = 10,
+    Refund = 11,
+}
+
+// One entry of the pod activity log
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct Activity {
+    pub action: ActivityAction,
+    pub timestamp: i64,
+    pub slot: u64,
+}
+
+/// Pod lifecycle. Every instruction that moves a pod goes through
+/// `Pod::transition`, which only allows the edges in `PodState::can_transition`.
+///
+/// Launched ─┬─> Hopping ─┬─> InTransitStar ────┐
+///           │            ├─> InTransitScatter ─┼─> Hopping | ReadyToLand
+///           │            └─> ReadyToLand ─> Landed
+///           └─> (any active state) ─> Emergency | Cancelled | Frozen
+///
+/// Frozen ─> Cancelled (refund), or back to Launched | Hopping | ReadyToLand when released.
+/// A release restores the state the pod was frozen from.
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum PodState {
+    #[default]
+    Launched,
+    Hopping,
+    InTransitStar,
+    InTransitScatter,
+    ReadyToLand,
+    Landed,
+    Cancelled,
+    Emergency,
+    Frozen,
+}
+impl PodState {
+    pub fn can_transition(self, to: PodState) -> bool {
+        use PodState::*;
+        matches!(
+            (self, to),
+            (Launched | Hopping, Hopping | InTransitStar | InTransitScatter | ReadyToLand)
+                | (InTransitStar | InTransitScatter, Hopping | ReadyToLand)
+                | (ReadyToLand, Landed)
+                | (Launched | Hopping | ReadyToLand, Emergency | Cancelled | Frozen)
+                | (Frozen, Launched | Hopping | ReadyToLand | Cancelled)
+        )
+    }
+
+    /// Pod can start a planet, star or scatter hop.
+    pub fn can_hop(self) -> bool {
+        matches!(self, PodState::Launched | PodState::Hopping)
+    }
+
+    pub fn is_in_transit(self) -> bool {
+        matches!(self, PodState::InTransitStar | PodState::InTransitScatter)
+    }
+
+    /// Pod is done and its account may be closed.
+    pub fn is_final(self) -> bool {
+        matches!(self, PodState::Landed | PodState::Cancelled | PodState::Emergency)
+    }
 }
 
 // Pod data
 #[account]
 #[repr(C)]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Pod {
     // 1-byte fields
     pub account_type: u8,
     pub version: u8, //version 1 default
     pub mode: u8,//1 Delay, 2 Instant, 3 Orbit (Manual)
-    pub next_process: u8, // (0 'hop'  1 'land')
-    pub last_process: u8, //('0 - launch pod', '1 - hop')
-    pub is_in_transit: u8, //flag to see if we are between star hop
+    pub state: PodState, // Lifecycle state
+    pub legs: u8, // Number of delivery legs (0 = single destination)
+    pub sealed: bool, // Destination holds a commitment, revealed at land time
+    pub frozen_from: PodState, // State restored when a freeze is released
+    pub freeze_reason: u8, // Compliance reason code of the last freeze (0 = never frozen)
+    pub indexed: bool, // Has an entry in its creator's pod meta (launched through `launch`)
 
     // 2-byte
-    pub id: u16, // used for star meta hops
     pub hops: u16, //The number of hops
 
     // 4-byte
     pub delay: u32, // Set delay in seconds
 
     // 8-byte fields
-    pub next_process_at: i64, //Next process timestamp
+    pub id: u64, // Program allocated, unique per creator (or schedule / commitment)
+    pub next_process_at: i64, //Next process timestamp (hop, or land once ReadyToLand)
     pub land_at: i64, //Set landing timestamp
     pub created_at: i64, //Pod launch timestamp
     pub last_process_at: i64, //Last updated timestamp
+    pub landed_at: i64, //Delivery timestamp (0 = not landed)
+    pub frozen_until: i64, //Compliance freeze expiry
     pub lamports: u64, //Lamports deposited
 
     // 32-byte fields
     pub location: Pubkey, // Current planet location
-    pub destination: [u8; 32], //Destination wallet address
+    pub destination: [u8; 32], //Destination wallet address (or commitment when sealed)
 
     // 6-byte alphanumeric emergency passcode hash (e.g., "A7X93B")
     pub passcode_hash: [u8; 32],
     pub authority: [u8; 32], //Authority wallet address
-}
\ No newline at end of file
+    pub source: [u8; 32], //Hash of the funding wallet, for compliance refunds
+    pub rent_payer: [u8; 32], //Hash of the wallet that paid the pod rent, refunded on close
+
+    // Activity ring buffer. Entry `activity_count % POD_ACTIVITY_LOG_SIZE` is written next.
+    pub activity_count: u32, //Total entries ever recorded
+    pub activity: [Activity; POD_ACTIVITY_LOG_SIZE],
+}
+
+/// Hop spacing multiplier for a delay.
+/// Every HOP_SCALE_PERIOD of delay adds one step, so a 7 day pod hops
+/// 7x less often than a 24 hour pod and takes a similar number of hops.
+pub fn hop_scale(delay: u32) -> u32 {
+    delay.div_ceil(HOP_SCALE_PERIOD).max(1)
+}
+
+/// Estimated number of hops a pod will take before landing.
+/// Delay pods hop roughly every HOP_INTERVAL_SECONDS (scaled for long delays),
+/// Instant pods hop once and Manual pods are driven by the creator.
+pub fn estimate_hops(mode: u8, delay: u32) -> u64 {
+    match mode {
+        1 => (delay / (HOP_INTERVAL_SECONDS * hop_scale(delay))) as u64,
+        2 => 1,
+        _ => 0,
+    }
+}
+
+impl Pod {
+    pub fn is_landed(&self) -> bool {
+        self.landed_at != 0
+    }
+
+    /// Moves the pod to `to`. Fails if the edge is not in the transition table.
+    pub fn transition(&mut self, to: PodState) -> Result<()> {
+        require!(self.state.can_transition(to), OridionError::InvalidPodTransition);
+        self.state = to;
+        Ok(())
+    }
+
+    /// Marks the pod as delivered, either `Landed` by `settle` or `Emergency`
+    /// by `user_settle`. A pod can only ever be delivered once.
+    pub fn land(&mut self, to: PodState, now: i64) -> Result<()> {
+        require!(!self.is_landed(), OridionError::AlreadyLanded);
+        require!(
+            matches!(to, PodState::Landed | PodState::Emergency | PodState::Cancelled),
+            OridionError::InvalidPodTransition
+        );
+        self.transition(to)?;
+        self.landed_at = now.max(1);
+        Ok(())
+    }
+
+    /// Seconds until the next hop: 2–4 minutes, stretched by `hop_scale` for long delays.
+    /// Derived from the pod and `slot`, inclusive of both ends.
+    pub fn hop_jitter(&self, slot: u64) -> i64 {
+        let scale = hop_scale(self.delay) as i64;
+        let (min_s, max_s) = (120 * scale, 240 * scale);
+        let h = hashv(&[
+            b"ORIDION_HOP_JITTER_V1",
+            &self.id.to_le_bytes(),
+            &self.hops.to_le_bytes(),
+            &self.created_at.to_le_bytes(),
+            &slot.to_le_bytes(),
+        ]).to_bytes();
+
+        let r = u64::from_le_bytes(h[0..8].try_into().unwrap());
+        let span = (max_s - min_s + 1) as u64;
+        min_s + (r % span) as i64
+    }
+
+    /// Appends an entry to the activity ring buffer, overwriting the oldest when full.
+    pub fn record(&mut self, action: ActivityAction, clock: &Clock) {
+        let index = self.activity_count as usize % POD_ACTIVITY_LOG_SIZE;
+        self.activity[index] = Activity {
+            action,
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+        };
+        self.activity_count = self.activity_count.saturating_add(1);
+    }
+
+    /// Recorded activity, oldest first. Only the last POD_ACTIVITY_LOG_SIZE entries are kept.
+    pub fn activity_log(&self) -> Vec<Activity> {
+        let len = (self.activity_count as usize).min(POD_ACTIVITY_LOG_SIZE);
+        let start = self.activity_count as usize - len;
+        (start..start + len)
+            .map(|i| self.activity[i % POD_ACTIVITY_LOG_SIZE])
+            .collect()
+    }
+
+    /// Commitment seeding a private pod. Binding the owner means a revealed
+    /// secret cannot be replayed by anyone else.
+    pub fn commitment(secret: &[u8; 32], owner: &Pubkey) -> [u8; 32] {
+        hashv(&[b"ORIDION_POD_COMMIT_V1", secret, owner.as_ref()]).to_bytes()
+    }
+
+    /// Hash of the wallet that funded `pod`. Salted by the pod so it cannot be
+    /// matched across pods without guessing the wallet.
+    pub fn source_hash(source: &Pubkey, pod: &Pubkey) -> [u8; 32] {
+        hashv(&[b"ORIDION_SOURCE_V1", source.as_ref(), pod.as_ref()]).to_bytes()
+    }
+
+    /// Compliance hold. Hops, landing and emergency landing are blocked until released.
+    pub fn freeze(&mut self, reason: u8, until: i64) -> Result<()> {
+        let from = self.state;
+        self.transition(PodState::Frozen)?;
+        self.frozen_from = from;
+        self.freeze_reason = reason;
+        self.frozen_until = until;
+        Ok(())
+    }
+
+    /// Lifts a freeze. Compliance can release at any time; anyone once it expired.
+    pub fn release(&mut self, by_compliance: bool, now: i64) -> Result<()> {
+        require!(self.state == PodState::Frozen, OridionError::PodNotFrozen);
+        require!(by_compliance || now >= self.frozen_until, OridionError::FreezeActive);
+        self.transition(self.frozen_from)?;
+        self.frozen_until = 0;
+        Ok(())
+    }
+
+    /// Notes and disclosures can only be attached in the launch transaction.
+    pub fn require_launching(&self, now: i64) -> Result<()> {
+        require!(
+            self.state == PodState::Launched && self.created_at == now,
+            OridionError::NoteNotAllowed
+        );
+        Ok(())
+    }
+
+    /// Commitment stored in place of a sealed destination.
+    pub fn destination_commitment(destination: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[b"ORIDION_DEST_V1", destination.as_ref(), salt]).to_bytes()
+    }
+
+    /// Checks `destination` is the pod's destination. Sealed pods need the reveal salt.
+    pub fn require_destination(&self, destination: &Pubkey, salt: Option<[u8; 32]>) -> Result<()> {
+        let expect = match (self.sealed, salt) {
+            (false, _) => destination.to_bytes(),
+            (true, Some(salt)) => Pod::destination_commitment(destination, &salt),
+            (true, None) => return err!(OridionError::InvalidDestination),
+        };
+        require!(expect == self.destination, OridionError::InvalidDestination);
+        Ok(())
+    }
+
+    /// Proves ownership of a private pod seeded by `commitment`.
+    /// Either `secret` opens the commitment for `signer`, or `signer` is the pod authority.
+    pub fn require_owner(&self, commitment: &[u8; 32], secret: Option<[u8; 32]>, signer: &Pubkey) -> Result<()> {
+        let owns = match secret {
+            Some(secret) => Pod::commitment(&secret, signer) == *commitment,
+            None => nonzero_32(&self.authority) && self.authority == signer.to_bytes(),
+        };
+        require!(owns, OridionError::InvalidPodProof);
+        Ok(())
+    }
+
+    /// Proves ownership of a pod spawned by a schedule. `signer` is the schedule creator, whose hash is the pod
+    /// source, or the pod authority. Works after the schedule itself has been closed.
+    pub fn require_scheduled_owner(&self, key: &Pubkey, signer: &Pubkey) -> Result<()> {
+        let owns = Pod::source_hash(signer, key) == self.source
+            || (nonzero_32(&self.authority) && self.authority == signer.to_bytes());
+        require!(owns, OridionError::InvalidPodProof);
+        Ok(())
+    }
+
+    /// Checks `planet` is where the pod currently sits.
+    pub fn require_at(&self, planet: &Pubkey) -> Result<()> {
+        require_keys_eq!(self.location, *planet, OridionError::PlanetNotPodLocation);
+        Ok(())
+    }
+
+    /// The land ticket the book holds for this pod once it is ready to land. `key` is the pod's own address.
+    pub fn land_ticket(&self, key: &Pubkey) -> [u8; 16] {
+        token_from(key, self.id, self.lamports, self.created_at)
+    }
+
+    /// Writes launch state for a new pod sitting on `location`.
+    pub fn init_launch(&mut self, id: u64, args: &PodArgs, location: Pubkey, now: i64, land_at: i64, source: [u8; 32]) {
+        self.account_type = AccountType::Pod as u8;
+        self.version = 1;
+        self.mode = args.m;
+        self.id = id;
+        self.created_at = now;
+        self.last_process_at = now;
+        self.hops = 1;
+        self.lamports = args.l;
+        self.state = PodState::Launched;
+        self.delay = args.d;
+        self.land_at = land_at;
+        self.passcode_hash = args.p; // pass code hash: [u8; 32]
+        self.landed_at = 0;
+        self.activity_count = 0;
+        self.activity = Default::default();
+        self.destination = combine_halves(args.de1, args.de2);
+        self.sealed = args.s;
+        self.authority = combine_halves(args.au1, args.au2);
+        self.source = source;
+        self.frozen_from = PodState::Launched;
+        self.freeze_reason = 0;
+        self.frozen_until = 0;
+        self.location = location;
+
+        //Depending on the land_at timestamp, set the next hop processing timestamp.
+        //Every pod hops at least once; that hop moves it to ReadyToLand when landing is close.
+        if args.m == 3 {
+            self.next_process_at = 0 // always;
+        } else if (now + 180) > land_at {
+            //Landing hop is next.
+            self.next_process_at = land_at;
+        } else {
+            //Hop is next.
+            self.next_process_at = now + 180;
+        }
+    }
+}
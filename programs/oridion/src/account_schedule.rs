@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use crate::oridion::{PodArgs, ScheduleArgs};
+use super::*;
+use crate::errors::OridionError;
+
+// Creates a schedule funded up front for `n` recurring pods
+#[derive(Accounts)]
+#[instruction(id: u16, args: ScheduleArgs)]
+pub struct CreateSchedule<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Schedule::INIT_SPACE,
+        seeds = [b"schedule", creator.key().as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub schedule: Account<'info, Schedule>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PodMeta::INIT_SPACE,
+        seeds = [b"pod_meta", creator.key().as_ref()],
+        bump
+    )]
+    pub pod_meta: Account<'info, PodMeta>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    /// CHECK: Denylist PDAs. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", creator.key().as_ref()], bump)]
+    pub creator_deny: UncheckedAccount<'info>,
+    /// CHECK: See `creator_deny`. Sealed destinations are screened at land time.
+    #[account(seeds = [b"deny", args.pod.de1.as_ref(), args.pod.de2.as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Crank - spawns the next pod of a schedule.
+// Spawned pods are seeded by the schedule instead of the creator.
+#[derive(Accounts)]
+pub struct SpawnPod<'info> {
+    #[account(
+        mut,
+        seeds = [b"schedule", schedule.creator.as_ref(), &schedule.id.to_le_bytes()],
+        bump = schedule.bump
+    )]
+    pub schedule: Account<'info, Schedule>,
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + Pod::INIT_SPACE,
+        seeds = [b"pod", schedule.key().as_ref(), &schedule.spawned.to_le_bytes()],
+        bump
+    )]
+    pub pod: Account<'info, Pod>,
+    #[account(mut)]
+    pub planet: Account<'info, Planet>,
+    // Deposit limits are counted per spawned pod. The creator keeps the meta open while the schedule runs.
+    #[account(mut, seeds = [b"pod_meta", schedule.creator.as_ref()], bump)]
+    pub pod_meta: Account<'info, PodMeta>,
+    #[account(mut, seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    /// CHECK: Denylist PDA of the schedule creator, screened on every spawn.
+    #[account(seeds = [b"deny", schedule.creator.as_ref()], bump)]
+    pub creator_deny: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Creator cancels a schedule. Unspawned funds and rent return to the creator.
+#[derive(Accounts)]
+#[instruction(id: u16)]
+pub struct CancelSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [b"schedule", creator.key().as_ref(), &id.to_le_bytes()],
+        bump = schedule.bump,
+        close = creator
+    )]
+    pub schedule: Account<'info, Schedule>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+// Emergency land of a spawned pod by the schedule creator or the pod authority.
+// Keyed by the schedule address so it still works once the schedule is closed.
+// Pod and note rent return to the manager that paid the pod rent on spawn.
+#[derive(Accounts)]
+#[instruction(schedule: Pubkey, index: u16)]
+pub struct EmergencyLandScheduled<'info> {
+    #[account(
+        mut,
+        seeds = [b"pod", schedule.as_ref(), &index.to_le_bytes()],
+        bump,
+        close = rent_to
+    )]
+    pub pod: Account<'info, Pod>,
+    #[account(
+        mut,
+        seeds = [b"pod_note", pod.key().as_ref()],
+        bump = pod_note.bump,
+        close = rent_to
+    )]
+    pub pod_note: Option<Account<'info, PodNote>>,
+
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
+
+    #[account(mut)]
+    pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+
+    /// Schedule creator or pod authority
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        constraint = Pod::source_hash(&rent_to.key(), &pod.key()) == pod.rent_payer @ OridionError::RentPayerMismatch
+    )]
+    pub rent_to: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    /// CHECK: Denylist PDA of the destination. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", destination.key().as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
+}
+
+// Recurring pod schedule
+#[account]
+#[derive(InitSpace, Default)]
+pub struct Schedule {
+    pub creator: Pubkey, // Creator wallet
+    pub id: u16, // Creator chosen schedule id
+    pub periods: u16, // Total pods to spawn
+    pub spawned: u16, // Pods spawned so far
+    pub delay: u32, // Delay for every spawned pod
+    pub interval: u32, // Seconds between spawns
+    pub lamports: u64, // Deposit per spawned pod
+    pub fee: u64, // Prepaid fee per spawned pod
+    pub created_at: i64,
+    pub next_spawn_at: i64, // Next spawn timestamp
+    pub destination: [u8; 32], // Destination wallet address (or commitment when sealed)
+    pub sealed: bool,
+    pub passcode_hash: [u8; 32],
+    pub authority: [u8; 32],
+    pub bump: u8,
+}
+
+impl Schedule {
+    /// Claims the next spawn once it is due and counts its deposit against the creator volume
+    /// and the daily inflow. Returns the index of the pod to spawn.
+    pub fn spawn_next(&mut self, meta: &mut PodMeta, universe: &mut Universe, now: i64) -> Result<u16> {
+        require!(self.spawned < self.periods, OridionError::ScheduleComplete);
+        require!(now >= self.next_spawn_at, OridionError::ScheduleNotDue);
+        universe.count_deposit(Some(meta), self.lamports, now)?;
+
+        let index = self.spawned;
+        self.spawned += 1;
+        self.next_spawn_at = self.next_spawn_at.saturating_add(self.interval as i64);
+        Ok(index)
+    }
+
+    /// Pod args used for every spawned pod (always Delay mode).
+    pub fn pod_args(&self) -> PodArgs {
+        let mut de1 = [0u8; 16];
+        let mut de2 = [0u8; 16];
+        let mut au1 = [0u8; 16];
+        let mut au2 = [0u8; 16];
+        de1.copy_from_slice(&self.destination[..16]);
+        de2.copy_from_slice(&self.destination[16..]);
+        au1.copy_from_slice(&self.authority[..16]);
+        au2.copy_from_slice(&self.authority[16..]);
+        PodArgs {
+            l: self.lamports,
+            m: 1,
+            d: self.delay,
+            p: self.passcode_hash,
+            de1,
+            de2,
+            au1,
+            au2,
+            s: self.sealed,
+        }
+    }
+}
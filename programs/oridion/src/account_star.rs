@@ -1,15 +1,76 @@
 use anchor_lang::prelude::*;
+use super::*;
+use crate::errors::OridionError;
 
-#[account]
-#[derive(InitSpace)]
-pub struct StarMeta {
-    pub to_pdas: [Pubkey; 3],
-    pub bump: u8,
+/// STARS PDA - Registered once and reused by every star hop
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateStar<'info> {
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + Star::INIT_SPACE,
+        seeds = [
+            STAR_SEED_PRE,
+            name.as_ref(),
+            STAR_SEED_POST
+        ],
+        bump
+    )]
+    pub star: Account<'info, Star>,
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Retires a free star from the pool
+#[derive(Accounts)]
+pub struct DeleteStar<'info> {
+    #[account(
+        mut,
+        close = manager,
+        constraint = star.held_by == Pubkey::default() @ OridionError::StarInUse
+    )]
+    pub star: Account<'info, Star>,
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct Star {
-    pub amount: u64,
-    pub manager: Pubkey
+    pub amount: u64, // Pod lamports held for the current hop
+    pub held_by: Pubkey, // Pod mid-hop through this star, default when free
+    pub held_at: i64,
+    pub bump: u8,
+}
+impl Star {
+    /// Takes `amount` of `pod`'s lamports for a star hop. The star must be free.
+    /// Holds never expire: the star carries pod funds until the hop ends.
+    pub fn hold(&mut self, pod: Pubkey, amount: u64, now: i64) -> Result<()> {
+        require_keys_eq!(self.held_by, Pubkey::default(), OridionError::StarInUse);
+        require!(amount > 0, OridionError::StarHopCalculationError);
+        self.amount = amount;
+        self.held_by = pod;
+        self.held_at = now;
+        Ok(())
+    }
+
+    /// Frees the star at the end of `pod`'s hop and returns the amount it held.
+    pub fn release(&mut self, pod: &Pubkey) -> Result<u64> {
+        require_keys_eq!(self.held_by, *pod, OridionError::StarNotHeld);
+        let amount = self.amount;
+        self.amount = 0;
+        self.held_by = Pubkey::default();
+        self.held_at = 0;
+        Ok(amount)
+    }
+}
+
+/// Stars passed to a hop must be distinct accounts.
+pub fn require_unique_stars(stars: &[Pubkey]) -> Result<()> {
+    for (i, star) in stars.iter().enumerate() {
+        require!(!stars[i + 1..].contains(star), OridionError::HopErrorStarsMustBeUnique);
+    }
+    Ok(())
 }
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use super::*;
+use crate::errors::OridionError;
 
 #[derive(Accounts)]
 pub struct InitializeTreasury<'info> {
@@ -17,19 +19,472 @@ pub struct InitializeTreasury<'info> {
 
 #[derive(Accounts)]
 pub struct WithdrawFromTreasury<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
     #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    #[account(mut, address = treasury.authority)]
+    pub manager: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Authority sets the guardian and payout limits, or applies a queued change
+#[derive(Accounts)]
+pub struct ConfigureTreasury<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(address = treasury.authority)]
+    pub authority: Signer<'info>,
+}
+
+// Authority queues a payout behind the timelock
+#[derive(Accounts)]
+pub struct ProposePayout<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingPayout::INIT_SPACE,
+        seeds = [b"payout".as_ref(), &treasury.next_proposal.to_le_bytes()],
+        bump
+    )]
+    pub payout: Account<'info, PendingPayout>,
+    pub recipient: SystemAccount<'info>,
+    #[account(mut, address = treasury.authority)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// Authority executes a queued payout once its delay has passed
+#[derive(Accounts)]
+pub struct ExecutePayout<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
     pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        seeds = [b"payout".as_ref(), &payout.id.to_le_bytes()],
+        bump = payout.bump,
+        has_one = recipient,
+        close = authority
+    )]
+    pub payout: Account<'info, PendingPayout>,
     #[account(mut)]
     pub recipient: SystemAccount<'info>,
     #[account(mut, address = treasury.authority)]
-    pub manager: Signer<'info>, 
+    pub authority: Signer<'info>,
+}
+
+// Guardian (or the authority) drops a queued payout. Rent returns to the authority.
+#[derive(Accounts)]
+pub struct CancelPayout<'info> {
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        seeds = [b"payout".as_ref(), &payout.id.to_le_bytes()],
+        bump = payout.bump,
+        close = authority
+    )]
+    pub payout: Account<'info, PendingPayout>,
+    /// CHECK: Receives the proposal rent
+    #[account(mut, address = treasury.authority)]
+    pub authority: UncheckedAccount<'info>,
+    #[account(
+        constraint = signer.key() == treasury.guardian
+            || signer.key() == treasury.authority @ OridionError::NotTreasuryGuardian
+    )]
+    pub signer: Signer<'info>,
+}
+
+// Guardian (or the authority) drops every queued treasury change
+#[derive(Accounts)]
+pub struct CancelTreasuryConfig<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        constraint = signer.key() == treasury.guardian
+            || signer.key() == treasury.authority @ OridionError::NotTreasuryGuardian
+    )]
+    pub signer: Signer<'info>,
+}
+
+// Pending authority accepts the treasury
+#[derive(Accounts)]
+pub struct AcceptTreasury<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(address = treasury.pending_authority @ OridionError::NotPendingAuthority)]
+    pub authority: Signer<'info>,
+}
+
+// Sweeps split fee shares to their recipients. Recipients with no share may be omitted.
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, address = treasury.split.operator)]
+    pub operator: Option<SystemAccount<'info>>,
+    #[account(mut, address = treasury.split.insurance)]
+    pub insurance: Option<SystemAccount<'info>>,
+    #[account(mut, address = treasury.split.partner)]
+    pub partner: Option<SystemAccount<'info>>,
+}
+
+// Treasury authority registers a referral partner
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RegisterPartner<'info> {
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Partner::INIT_SPACE,
+        seeds = [b"partner", wallet.as_ref()],
+        bump
+    )]
+    pub partner: Account<'info, Partner>,
+    #[account(mut, address = treasury.authority)]
+    pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct UpdatePartner<'info> {
+    #[account(seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, seeds = [b"partner", partner.wallet.as_ref()], bump = partner.bump)]
+    pub partner: Account<'info, Partner>,
+    #[account(address = treasury.authority)]
+    pub authority: Signer<'info>,
+}
+
+// Partner wallet claims its accrued fees
+#[derive(Accounts)]
+pub struct ClaimPartner<'info> {
+    #[account(mut, seeds = [b"partner", wallet.key().as_ref()], bump = partner.bump)]
+    pub partner: Account<'info, Partner>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+/// Shares of collected fees paid out by `distribute`. The treasury keeps the remainder.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct FeeSplit {
+    pub operator: Pubkey, // Gas reimbursement for the operator
+    pub insurance: Pubkey, // Insurance fund
+    pub partner: Pubkey, // Partner share
+    pub operator_bps: u16,
+    pub insurance_bps: u16,
+    pub partner_bps: u16,
+}
+
+impl FeeSplit {
+    pub fn validate(&self) -> Result<()> {
+        let total = self.operator_bps as u64 + self.insurance_bps as u64 + self.partner_bps as u64;
+        require!(total <= BPS_DENOMINATOR, OridionError::InvalidFeeSplit);
+        for (bps, to) in [
+            (self.operator_bps, self.operator),
+            (self.insurance_bps, self.insurance),
+            (self.partner_bps, self.partner),
+        ] {
+            require!(bps == 0 || to != Pubkey::default(), OridionError::InvalidFeeSplit);
+        }
+        Ok(())
+    }
+
+    /// True when `next` pays the same recipients and no share grows.
+    pub fn tightened_by(&self, next: &FeeSplit) -> bool {
+        next.operator == self.operator
+            && next.insurance == self.insurance
+            && next.partner == self.partner
+            && next.operator_bps <= self.operator_bps
+            && next.insurance_bps <= self.insurance_bps
+            && next.partner_bps <= self.partner_bps
+    }
+
+    /// Operator, insurance and partner shares of `fees`, rounded down.
+    pub fn shares(&self, fees: u64) -> [u64; 3] {
+        let share = |bps: u16| (fees as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        [share(self.operator_bps), share(self.insurance_bps), share(self.partner_bps)]
+    }
+}
+
+/// Payout limits. A zero cap or threshold is not enforced.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PayoutLimits {
+    pub epoch_cap: u64, // Max lamports paid out directly per epoch
+    pub timelock_above: u64, // Payouts above this must be proposed first
+    pub timelock_delay: u32, // Seconds between propose and execute
+}
+
+impl PayoutLimits {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.timelock_delay <= MAX_DELAY_CEILING, OridionError::InvalidPayoutLimits);
+        if self.timelock_above > 0 {
+            require!(self.timelock_delay > 0, OridionError::InvalidPayoutLimits);
+        }
+        Ok(())
+    }
+
+    /// True when `next` is at least as strict as these limits on every axis.
+    pub fn tightened_by(&self, next: &PayoutLimits) -> bool {
+        let ceiling = |v: u64| if v == 0 { u64::MAX } else { v };
+        ceiling(next.epoch_cap) <= ceiling(self.epoch_cap)
+            && ceiling(next.timelock_above) <= ceiling(self.timelock_above)
+            && next.timelock_delay >= self.timelock_delay
+    }
+}
+
 #[account]
 #[repr(C)]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Treasury {
     pub bump: u8,
-    pub authority: Pubkey
-}
\ No newline at end of file
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey, // Set by `transfer_treasury`, cleared on accept
+    pub guardian: Pubkey, // Can cancel queued payouts
+    pub limits: PayoutLimits,
+    pub epoch: u64, // Epoch `paid_in_epoch` belongs to
+    pub paid_in_epoch: u64,
+    pub next_proposal: u64,
+    pub split: FeeSplit,
+    pub undistributed: u64, // Fees collected since the last `distribute`
+    pub pending_guardian: Pubkey, // Queued by `configure_treasury`
+    pub pending_limits: PayoutLimits,
+    pub config_after: i64, // When the queued change may apply, 0 when none is queued
+    pub pending_split: FeeSplit, // Queued by `configure_fee_split`
+    pub authority_after: i64, // When the pending authority may accept
+}
+
+impl Treasury {
+    /// Records a fee paid into the treasury.
+    pub fn accrue(&mut self, fee: u64) -> Result<()> {
+        self.undistributed = self.undistributed.checked_add(fee).ok_or(OridionError::UnusualMathError)?;
+        Ok(())
+    }
+
+    /// Lamports owed to split recipients on the next `distribute`.
+    pub fn reserved(&self) -> u64 {
+        self.split.shares(self.undistributed).iter().sum()
+    }
+
+    /// Checks a payout of `amount` from a treasury holding `balance` lamports.
+    /// The treasury never drops below `rent_floor` or spends undistributed shares. Direct payouts count towards the
+    /// epoch cap and may not exceed the timelock threshold; timelocked ones already waited.
+    pub fn check_payout(&mut self, amount: u64, balance: u64, rent_floor: u64, epoch: u64, timelocked: bool) -> Result<()> {
+        require!(
+            amount <= balance.saturating_sub(rent_floor).saturating_sub(self.reserved()),
+            OridionError::InsufficientTreasuryBalance
+        );
+        if timelocked {
+            return Ok(());
+        }
+        require!(
+            self.limits.timelock_above == 0 || amount <= self.limits.timelock_above,
+            OridionError::PayoutNeedsTimelock
+        );
+        if self.limits.epoch_cap > 0 {
+            if epoch != self.epoch {
+                self.epoch = epoch;
+                self.paid_in_epoch = 0;
+            }
+            let paid = self.paid_in_epoch.saturating_add(amount);
+            require!(paid <= self.limits.epoch_cap, OridionError::PayoutCapExceeded);
+            self.paid_in_epoch = paid;
+        }
+        Ok(())
+    }
+
+    /// Sets the guardian and payout limits. Stricter limits under the same guardian apply at once;
+    /// anything that relaxes a limit or replaces the guardian is queued behind the current timelock delay.
+    /// Returns whether the change took effect.
+    pub fn propose_config(&mut self, guardian: Pubkey, limits: PayoutLimits, now: i64) -> Result<bool> {
+        limits.validate()?;
+        if guardian == self.guardian && self.limits.tightened_by(&limits) {
+            self.limits = limits;
+            if self.config_after > 0 {
+                self.pending_limits = limits;
+            }
+            return Ok(true);
+        }
+        self.queue_config(now)?;
+        self.pending_guardian = guardian;
+        self.pending_limits = limits;
+        Ok(false)
+    }
+
+    /// Sets the fee split. A split paying the same recipients no more applies at once;
+    /// a raised share or a new recipient is queued with the guardian and limits.
+    /// Returns whether the change took effect.
+    pub fn propose_split(&mut self, split: FeeSplit, now: i64) -> Result<bool> {
+        split.validate()?;
+        if self.split.tightened_by(&split) {
+            self.split = split;
+            if self.config_after > 0 {
+                self.pending_split = split;
+            }
+            return Ok(true);
+        }
+        self.queue_config(now)?;
+        self.pending_split = split;
+        Ok(false)
+    }
+
+    /// Starts or restarts the queue. A fresh queue starts from the live configuration,
+    /// so applying it only changes what was proposed.
+    fn queue_config(&mut self, now: i64) -> Result<()> {
+        if self.config_after == 0 {
+            self.pending_guardian = self.guardian;
+            self.pending_limits = self.limits;
+            self.pending_split = self.split;
+        }
+        self.config_after = now.checked_add(self.limits.timelock_delay as i64).ok_or(OridionError::UnusualMathError)?;
+        Ok(())
+    }
+
+    /// Applies the queued guardian, limits and fee split once their delay has passed.
+    pub fn apply_config(&mut self, now: i64) -> Result<()> {
+        require!(self.config_after > 0, OridionError::NoPendingConfig);
+        require!(now >= self.config_after, OridionError::PayoutLocked);
+        self.guardian = self.pending_guardian;
+        self.limits = self.pending_limits;
+        self.split = self.pending_split;
+        self.clear_config();
+        Ok(())
+    }
+
+    /// Proposes a new authority. It may accept once the timelock delay has passed.
+    pub fn propose_authority(&mut self, authority: Pubkey, now: i64) -> Result<()> {
+        self.pending_authority = authority;
+        self.authority_after = now.checked_add(self.limits.timelock_delay as i64).ok_or(OridionError::UnusualMathError)?;
+        Ok(())
+    }
+
+    /// Hands the treasury to the pending authority. Returns the previous authority.
+    pub fn accept_authority(&mut self, now: i64) -> Result<Pubkey> {
+        require!(now >= self.authority_after, OridionError::PayoutLocked);
+        let previous = self.authority;
+        self.authority = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        self.authority_after = 0;
+        Ok(previous)
+    }
+
+    /// Drops every queued change: guardian, limits, fee split and a pending authority.
+    pub fn cancel_config(&mut self) -> Result<()> {
+        require!(
+            self.config_after > 0 || self.pending_authority != Pubkey::default(),
+            OridionError::NoPendingConfig
+        );
+        self.clear_config();
+        self.pending_authority = Pubkey::default();
+        self.authority_after = 0;
+        Ok(())
+    }
+
+    fn clear_config(&mut self) {
+        self.pending_guardian = Pubkey::default();
+        self.pending_limits = PayoutLimits::default();
+        self.pending_split = FeeSplit::default();
+        self.config_after = 0;
+    }
+}
+
+/// Referral partner. Accrued fees are held in this PDA until claimed.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct Partner {
+    pub wallet: Pubkey,
+    pub bps: u16, // Share of each referred launch fee
+    pub active: bool,
+    pub accrued: u64, // Claimable lamports
+    pub earned: u64, // Lifetime lamports
+    pub bump: u8,
+}
+
+impl Partner {
+    /// Partner cut of a launch fee. Paused partners earn nothing.
+    pub fn share_of(&self, fee: u64) -> u64 {
+        if !self.active {
+            return 0;
+        }
+        (fee as u128 * self.bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    pub fn accrue(&mut self, share: u64) -> Result<()> {
+        self.accrued = self.accrued.checked_add(share).ok_or(OridionError::UnusualMathError)?;
+        self.earned = self.earned.saturating_add(share);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingPayout {
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub execute_after: i64,
+    pub bump: u8,
+}
+
+#[event]
+pub struct PayoutProposed {
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub execute_after: i64,
+}
+
+#[event]
+pub struct PayoutExecuted {
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryAuthorityChanged {
+    pub previous: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct FeesDistributed {
+    pub fees: u64,
+    pub operator: u64,
+    pub insurance: u64,
+    pub partner: u64,
+}
+
+#[event]
+pub struct PartnerClaimed {
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PayoutCancelled {
+    pub id: u64,
+    pub by: Pubkey,
+}
+
+#[event]
+pub struct TreasuryConfigQueued {
+    pub guardian: Pubkey,
+    pub limits: PayoutLimits,
+    pub apply_after: i64,
+}
+
+#[event]
+pub struct FeeSplitQueued {
+    pub split: FeeSplit,
+    pub apply_after: i64,
+}
+
+#[event]
+pub struct TreasuryConfigCancelled {
+    pub by: Pubkey,
+}
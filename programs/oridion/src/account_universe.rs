@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use super::*;
 use crate::errors::OridionError;
+use crate::oridion::PodArgs;
 
 /// BIG BANG UNIVERSE PDA
 #[derive(Accounts)]
@@ -27,9 +28,112 @@ pub struct UpdateUniverse<'info> {
     pub creator: Signer<'info>
 }
 
+/// Read-only view of the universe used to quote launches.
+#[derive(Accounts)]
+pub struct QuoteFee<'info> {
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+}
+
+/// Returned by `quote` through return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PodQuote {
+    pub fee: u64, // fee in lamports charged on top of the deposit
+    pub hops: u64, // estimated hop count used for the fee
+    pub land_at: i64, // landing timestamp if launched now
+}
+
+/// Fee parameters for a single pod mode.
+/// fee = clamp(base + lamports * bps / 10_000 + hops * per_hop, min, max)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct FeeModel {
+    pub base: u64, // flat fee in lamports
+    pub bps: u16, // basis points of the deposit
+    pub per_hop: u64, // lamports per estimated hop
+    pub min: u64, // fee floor in lamports
+    pub max: u64, // fee cap in lamports (0 = no cap)
+}
+
+impl FeeModel {
+    /// Validates the model before it is stored.
+    pub fn validate(&self) -> Result<()> {
+        require!(self.bps as u64 <= BPS_DENOMINATOR, OridionError::InvalidFeeModel);
+        require!(self.max == 0 || self.max >= self.min, OridionError::InvalidFeeModel);
+        Ok(())
+    }
+
+    /// Computes the fee for a deposit of `lamports` with `hops` estimated hops.
+    pub fn fee_for(&self, lamports: u64, hops: u64) -> Result<u64> {
+        let pct = (lamports as u128)
+            .checked_mul(self.bps as u128)
+            .ok_or(OridionError::UnusualMathError)?
+            / BPS_DENOMINATOR as u128;
+        let hop_fee = (hops as u128)
+            .checked_mul(self.per_hop as u128)
+            .ok_or(OridionError::UnusualMathError)?;
+        let raw = (self.base as u128)
+            .checked_add(pct)
+            .and_then(|v| v.checked_add(hop_fee))
+            .ok_or(OridionError::UnusualMathError)?;
+
+        let mut fee = raw.max(self.min as u128);
+        if self.max > 0 {
+            fee = fee.min(self.max as u128);
+        }
+        u64::try_from(fee).map_err(|_| error!(OridionError::UnusualMathError))
+    }
+}
+
+/// Allowed delay range in seconds for a single pod mode.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct DelayBounds {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl DelayBounds {
+    /// Validates the bounds before they are stored.
+    pub fn validate(&self, mode: u8) -> Result<()> {
+        require!(self.min <= self.max, OridionError::InvalidDelay);
+        require!(self.max <= MAX_DELAY_CEILING, OridionError::InvalidDelay);
+        // Delay mode must always land in the future
+        if mode == 1 {
+            require!(self.min > 0, OridionError::InvalidDelay);
+        }
+        Ok(())
+    }
+
+    pub fn check(&self, delay: u32) -> Result<()> {
+        require!(delay >= self.min, OridionError::DelayTooShort);
+        require!(delay <= self.max, OridionError::DelayTooLong);
+        Ok(())
+    }
+}
+
+/// Deposit limits. A zero limit is not enforced.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct DepositLimits {
+    pub max_pod: u64, // Max lamports per pod
+    pub creator_volume: u64, // Max lamports per creator within `creator_window`
+    pub creator_window: u32, // Rolling window in seconds
+    pub daily_inflow: u64, // Max lamports deposited protocol-wide per UTC day
+}
+
+impl DepositLimits {
+    pub fn validate(&self) -> Result<()> {
+        if self.creator_volume > 0 {
+            require!(
+                self.creator_window > 0 && self.creator_window <= MAX_DELAY_CEILING,
+                OridionError::InvalidLimits
+            );
+        }
+        Ok(())
+    }
+}
+
 #[account]
 #[repr(C)]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Universe {
     pub account_type: u8,
     // Indicates whether the fee can be modified. 0 = unlocked, 1 = locked.
@@ -37,6 +141,106 @@ pub struct Universe {
     pub bump: u8, // Bump
     pub created: i64, //Universe started
     pub last_updated: i64, //Last updated (used for comet random id)
-    pub fee: u64, // pod base fee in lamports
-    pub increment: u64, // fee increment
+    pub fees: [FeeModel; 3], // fee model per mode (Delay, Instant, Manual)
+    pub delays: [DelayBounds; 3], // delay bounds per mode (Delay, Instant, Manual)
+    pub compliance_key: [u8; 32], // x25519 key pod disclosures are encrypted to (zero = unset)
+    pub compliance_authority: Pubkey, // Manages the denylist (default = nobody)
+    pub limits: DepositLimits,
+    pub inflow_day: i64, // UTC day `inflow_today` belongs to
+    pub inflow_today: u64, // Lamports deposited during `inflow_day`
+    pub lock_expiry: u32, // Seconds a planet lock stays live (0 = LOCK_EXPIRE_SECONDS)
+    pub next_pod_id: u64, // Id of the next launched pod. Kept here so closing a pod meta never resets it.
+}
+
+impl Universe {
+    /// Flat `fee` for every mode, plus `per_hop` lamports per estimated hop in Delay mode.
+    pub fn flat_fees(fee: u64, per_hop: u64) -> [FeeModel; 3] {
+        let base_fee = FeeModel { base: fee, ..FeeModel::default() };
+        [
+            FeeModel { per_hop, ..base_fee }, // Delay - increment per hop
+            base_fee, // Instant
+            base_fee, // Manual
+        ]
+    }
+
+    pub fn default_delays() -> [DelayBounds; 3] {
+        [
+            DelayBounds { min: MIN_DELAY_MODE_DELAY, max: MAX_DELAY_ALLOWED }, // Delay
+            DelayBounds { min: 0, max: MAX_DELAY_ALLOWED }, // Instant
+            DelayBounds { min: 0, max: MAX_DELAY_ALLOWED }, // Manual
+        ]
+    }
+
+    /// Returns the fee model for a pod mode (1 Delay, 2 Instant, 3 Manual).
+    pub fn fee_model(&self, mode: u8) -> Result<&FeeModel> {
+        require!((1..=3).contains(&mode), OridionError::InvalidMode);
+        Ok(&self.fees[(mode - 1) as usize])
+    }
+
+    /// Returns the delay bounds for a pod mode (1 Delay, 2 Instant, 3 Manual).
+    pub fn delay_bounds(&self, mode: u8) -> Result<&DelayBounds> {
+        require!((1..=3).contains(&mode), OridionError::InvalidMode);
+        Ok(&self.delays[(mode - 1) as usize])
+    }
+
+    /// Seconds after which planet locks expire.
+    pub fn lock_expiry(&self) -> i64 {
+        match self.lock_expiry {
+            0 => LOCK_EXPIRE_SECONDS,
+            seconds => seconds as i64,
+        }
+    }
+
+    /// Counts a deposit against the creator's rolling volume and the global daily inflow cap.
+    /// The creator's meta may only be left out while creator volume limits are off.
+    pub fn count_deposit(&mut self, meta: Option<&mut PodMeta>, lamports: u64, now: i64) -> Result<()> {
+        match meta {
+            Some(meta) => meta.add_volume(lamports, now, &self.limits)?,
+            None => require!(self.limits.creator_volume == 0, OridionError::PodMetaMissing),
+        }
+        self.add_inflow(lamports, now)
+    }
+
+    /// Counts a deposit against the global daily inflow cap.
+    pub fn add_inflow(&mut self, lamports: u64, now: i64) -> Result<()> {
+        let day = now / 86400;
+        if day != self.inflow_day {
+            self.inflow_day = day;
+            self.inflow_today = 0;
+        }
+        let total = self.inflow_today
+            .checked_add(lamports)
+            .ok_or(OridionError::UnusualMathError)?;
+        require!(
+            self.limits.daily_inflow == 0 || total <= self.limits.daily_inflow,
+            OridionError::DailyInflowExceeded
+        );
+        self.inflow_today = total;
+        Ok(())
+    }
+
+    /// Hands out the next pod id. Ids are global and never reused, so creator-seeded
+    /// pod PDAs stay unique after the creator closes their pod meta.
+    pub fn allocate_pod_id(&mut self) -> Result<u64> {
+        let id = self.next_pod_id;
+        self.next_pod_id = id.checked_add(1).ok_or(OridionError::UnusualMathError)?;
+        Ok(id)
+    }
+
+    /// Fee owed for a pod launched with the given lamports, mode and delay.
+    pub fn fee_for(&self, lamports: u64, mode: u8, delay: u32) -> Result<u64> {
+        let hops = estimate_hops(mode, delay);
+        self.fee_model(mode)?.fee_for(lamports, hops)
+    }
+
+    /// Validates launch arguments and prices the pod as if launched at `now`.
+    /// `quote` returns this as is; every launch path charges the same fee and lands at the same time.
+    pub fn plan_launch(&self, args: &PodArgs, now: i64) -> Result<PodQuote> {
+        let land_at = validate_pod_args(args, self, now)?;
+        Ok(PodQuote {
+            fee: self.fee_for(args.l, args.m, args.d)?,
+            hops: estimate_hops(args.m, args.d),
+            land_at,
+        })
+    }
 }
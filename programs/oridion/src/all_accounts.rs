@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::account_land::LandBook;
-use crate::account_star::{Star, StarMeta};
+use crate::account_star::Star;
 use super::*;
 
 //The signer (MANAGER_PUBKEY) must be the Oridion manager key
@@ -12,57 +12,41 @@ pub struct PlanetHop<'info> {
     pub to_planet: Account<'info, Planet>,
     #[account(mut)]
     pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
     #[account(
         mut,
         seeds = [b"land_book"],
         bump
     )]
     pub book: Account<'info, LandBook>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump
+    )]
+    pub pod_legs: Option<Account<'info, PodLegs>>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>
 }
 
-//Star hop from Planet to Split stars
+//Star hop from Planet to Split stars. Stars are free accounts from the registered pool.
 #[derive(Accounts)]
-#[instruction(star_one_id: String, star_two_id: String)]
 pub struct StarHopTwoStart<'info> {
     #[account(mut)]
     pub pod: Account<'info, Pod>,
 
     #[account(mut)]
     pub from_planet: Account<'info, Planet>,
-    #[account(init, payer = manager, space = 8 + Star::INIT_SPACE,
-        seeds = [
-            STAR_SEED_PRE,
-            star_one_id.as_ref(),
-            STAR_SEED_POST
-        ],
-        bump
-    )]
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(mut)]
     pub star_one: Account<'info, Star>,
-    #[account(init, payer = manager, space = 8 + Star::INIT_SPACE,
-        seeds = [
-            STAR_SEED_PRE,
-            star_two_id.as_ref(),
-            STAR_SEED_POST
-        ],
-        bump
-    )]
+    #[account(mut)]
     pub star_two: Account<'info, Star>,
 
-    #[account(
-        init,
-        seeds = [b"star_two", pod.key().as_ref()],
-        bump,
-        payer = manager,
-        space = 8 + StarMeta::INIT_SPACE
-    )]
-    pub star_meta: Account<'info, StarMeta>,
-
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>,
-    pub rent: Sysvar<'info, Rent>,
-    pub system_program: Program<'info, System>,
 }
 
 //Return from stars to the destination planet
@@ -72,9 +56,9 @@ pub struct StarHopTwoEnd<'info> {
     pub pod: Account<'info, Pod>,
     #[account(mut)]
     pub to_planet: Account<'info, Planet>,
-    #[account(mut, close = manager, has_one = manager, constraint = manager.key == &star_one.manager)]
+    #[account(mut)]
     pub star_one: Account<'info, Star>,
-    #[account(mut, close = manager, has_one = manager, constraint = manager.key == &star_two.manager)]
+    #[account(mut)]
     pub star_two: Account<'info, Star>,
 
     #[account(
@@ -83,14 +67,12 @@ pub struct StarHopTwoEnd<'info> {
         bump
     )]
     pub book: Account<'info, LandBook>,
-
     #[account(
         mut,
-        seeds = [b"star_two", pod.key().as_ref()],
-        bump = star_meta.bump,
-        close = manager
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump
     )]
-    pub star_meta: Account<'info, StarMeta>,
+    pub pod_legs: Option<Account<'info, PodLegs>>,
 
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>
@@ -98,52 +80,23 @@ pub struct StarHopTwoEnd<'info> {
 
 
 #[derive(Accounts)]
-#[instruction(star_one_id: String, star_two_id: String, star_three_id: String )]
 pub struct StarHopThreeStart<'info> {
     #[account(mut)]
     pub pod: Account<'info, Pod>,
 
     #[account(mut)]
     pub from_planet: Account<'info, Planet>,
-    #[account(init, payer = manager, space = 8 + Star::INIT_SPACE,
-        seeds = [
-            STAR_SEED_PRE,
-            star_one_id.as_ref(),
-            STAR_SEED_POST
-        ],
-        bump
-    )]
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(mut)]
     pub star_one: Account<'info, Star>,
-
-    #[account(init, payer = manager, space = 8 + Star::INIT_SPACE,
-        seeds = [
-            STAR_SEED_PRE,
-            star_two_id.as_ref(),
-            STAR_SEED_POST
-        ],
-        bump
-    )]
+    #[account(mut)]
     pub star_two: Account<'info, Star>,
-
-    #[account(init, payer = manager, space = 8 + Star::INIT_SPACE,
-        seeds = [
-            STAR_SEED_PRE,
-            star_three_id.as_ref(),
-            STAR_SEED_POST
-        ],
-        bump
-    )]
+    #[account(mut)]
     pub star_three: Account<'info, Star>,
 
-    /// CHECK: This account is manually created with `invoke_signed` using a verified PDA.
-    /// The PDA is derived from [b"star_three", pod.key()], and data is written manually using `.serialize()`.
-    /// We ensure the account is the correct target by checking the key before use.
-    #[account(mut)]
-    pub star_meta: UncheckedAccount<'info>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>,
-    pub rent: Sysvar<'info, Rent>,
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -152,11 +105,11 @@ pub struct StarHopThreeEnd<'info> {
     pub pod: Account<'info, Pod>,
     #[account(mut)]
     pub to_planet: Account<'info, Planet>,
-    #[account(mut, close = manager, has_one = manager, constraint = manager.key == &star_one.manager)]
+    #[account(mut)]
     pub star_one: Account<'info, Star>,
-    #[account(mut, close = manager, has_one = manager, constraint = manager.key == &star_two.manager)]
+    #[account(mut)]
     pub star_two: Account<'info, Star>,
-    #[account(mut, close = manager, has_one = manager, constraint = manager.key == &star_three.manager)]
+    #[account(mut)]
     pub star_three: Account<'info, Star>,
 
     #[account(
@@ -165,20 +118,52 @@ pub struct StarHopThreeEnd<'info> {
         bump
     )]
     pub book: Account<'info, LandBook>,
-
     #[account(
         mut,
-        seeds = [b"star_three", pod.key().as_ref()],
-        bump = star_meta.bump,
-        close = manager
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump
     )]
-    pub star_meta: Account<'info, StarMeta>,
+    pub pod_legs: Option<Account<'info, PodLegs>>,
+
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>
+}
+
+
 
+// Batch hop. Planets then pods are passed in `remaining_accounts`, all writable.
+#[derive(Accounts)]
+pub struct BatchHop<'info> {
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>
 }
 
+/// One pod's move inside a batch hop, by planet index.
+pub struct BatchMove {
+    pub from: usize,
+    pub to: usize,
+    pub lamports: u64,
+}
 
+/// Nets batch moves into one signed lamport flow per planet (negative = outflow).
+pub fn net_flows(planets: usize, moves: &[BatchMove]) -> Result<Vec<i128>> {
+    let mut flows = vec![0i128; planets];
+    for m in moves {
+        require!(m.from < planets && m.to < planets, OridionError::InvalidBatch);
+        require!(m.from != m.to, OridionError::HopErrorToAndFromAreSame);
+        flows[m.from] -= m.lamports as i128;
+        flows[m.to] += m.lamports as i128;
+    }
+    Ok(flows)
+}
 
 
 // Scatter Hop
@@ -191,6 +176,14 @@ pub struct TransitMeta {
     pub created_at: i64,
     pub bump: u8,
 }
+impl TransitMeta {
+    /// Checks the scatter started from `from` and `planets` are the recorded split planets.
+    pub fn require_sources(&self, from: &Pubkey, planets: &[Pubkey; 3]) -> Result<()> {
+        require_keys_eq!(self.from, *from, OridionError::ScatterMetaMismatch);
+        require!(self.to_pdas == *planets, OridionError::ScatterMetaMismatch);
+        Ok(())
+    }
+}
 
 #[derive(Accounts)]
 pub struct ScatterStart<'info> {
@@ -200,6 +193,8 @@ pub struct ScatterStart<'info> {
 
     #[account(mut)]
     pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
     #[account(mut)]
     pub to_planet_1: Account<'info, Planet>,
     #[account(mut)]
@@ -235,6 +230,8 @@ pub struct ScatterEnd<'info> {
     pub from_planet_2: Account<'info, Planet>,
     #[account(mut)]
     pub from_planet_3: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
 
     #[account(
         mut,
@@ -242,6 +239,12 @@ pub struct ScatterEnd<'info> {
         bump
     )]
     pub book: Account<'info, LandBook>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump
+    )]
+    pub pod_legs: Option<Account<'info, PodLegs>>,
 
     #[account(
         mut,
@@ -256,7 +259,7 @@ pub struct ScatterEnd<'info> {
 }
 
 
-/// Destination is checked through a token and cannot be changed.
+/// Destination is checked against the pod (or its sealed commitment) and cannot be changed.
 #[derive(Accounts)]
 pub struct LandAccount<'info> {
     #[account(
@@ -266,44 +269,229 @@ pub struct LandAccount<'info> {
     )]
     pub book: Account<'info, LandBook>,
     #[account(mut)]
+    pub pod: Account<'info, Pod>,
+    #[account(mut)]
     pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
     #[account(mut)]
     pub destination: SystemAccount<'info>,
+    /// CHECK: Denylist PDA of the destination. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", destination.key().as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
+    /// CHECK: Wallet that launched the pod, bound by `pod.source`. Required with the pod meta.
+    #[account(constraint = Pod::source_hash(&creator.key(), &pod.key()) == pod.source @ OridionError::NotPodCreator)]
+    pub creator: Option<UncheckedAccount<'info>>,
+    // Creator's pod index. Required for pods launched through `launch`.
+    #[account(mut, seeds = [b"pod_meta", key_or_default(&creator).as_ref()], bump)]
+    pub pod_meta: Option<Account<'info, PodMeta>>,
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", key_or_default(&creator).as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump
+    )]
+    pub pod_meta_page: Option<Account<'info, PodMetaPage>>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>
 }
 
 
+/// Lands a single leg of a split pod.
 #[derive(Accounts)]
-pub struct ClosePod<'info> {
+pub struct LandLeg<'info> {
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
     #[account(mut)]
-    pub pod_meta: Account<'info, PodMeta>,
+    pub pod: Account<'info, Pod>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump
+    )]
+    pub pod_legs: Account<'info, PodLegs>,
+    #[account(mut, address = pod.location @ OridionError::PlanetNotPodLocation)]
+    pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    /// CHECK: Denylist PDA of the destination. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", destination.key().as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
+    /// CHECK: Wallet that launched the pod, bound by `pod.source`. Required with the pod meta.
+    #[account(constraint = Pod::source_hash(&creator.key(), &pod.key()) == pod.source @ OridionError::NotPodCreator)]
+    pub creator: Option<UncheckedAccount<'info>>,
+    // Creator's pod index. Required for pods launched through `launch`.
+    #[account(mut, seeds = [b"pod_meta", key_or_default(&creator).as_ref()], bump)]
+    pub pod_meta: Option<Account<'info, PodMeta>>,
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", key_or_default(&creator).as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump
+    )]
+    pub pod_meta_page: Option<Account<'info, PodMetaPage>>,
+    #[account(mut, address = MANAGER_PUBKEY)]
+    pub manager: Signer<'info>
+}
+
+
+#[derive(Accounts)]
+pub struct ClosePod<'info> {
     #[account(mut,
-        close = manager,
-        constraint = pod.next_process == 1 @ OridionError::PodCloseError,
-        constraint = pod.is_in_transit == 0 @ OridionError::PodCloseError,
+        close = rent_to,
+        constraint = pod.state.is_final() @ OridionError::PodCloseError,
     )]
     pub pod: Account<'info, Pod>,
+    /// CHECK: Wallet that launched the pod, bound by `pod.source`. Required with the pod meta.
+    #[account(constraint = Pod::source_hash(&creator.key(), &pod.key()) == pod.source @ OridionError::NotPodCreator)]
+    pub creator: Option<UncheckedAccount<'info>>,
+    // Creator's pod index. Scheduled and private pods have no entry.
+    #[account(mut, seeds = [b"pod_meta", key_or_default(&creator).as_ref()], bump)]
+    pub pod_meta: Option<Account<'info, PodMeta>>,
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", key_or_default(&creator).as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump
+    )]
+    pub pod_meta_page: Option<Account<'info, PodMetaPage>>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump,
+        close = rent_to
+    )]
+    pub pod_legs: Option<Account<'info, PodLegs>>,
+    #[account(
+        mut,
+        seeds = [b"pod_note", pod.key().as_ref()],
+        bump = pod_note.bump,
+        close = rent_to
+    )]
+    pub pod_note: Option<Account<'info, PodNote>>,
     #[account(
         mut,
         seeds = [b"land_book"],
         bump
     )]
     pub book: Account<'info, LandBook>,
+    // Wallet that paid the pod rent at launch
+    #[account(
+        mut,
+        constraint = Pod::source_hash(&rent_to.key(), &pod.key()) == pod.rent_payer @ OridionError::RentPayerMismatch
+    )]
+    pub rent_to: SystemAccount<'info>,
     #[account(mut, address = MANAGER_PUBKEY)]
     pub manager: Signer<'info>,
 }
 
 
+// Creator closes their own finished pod
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreatorClosePod<'info> {
+    #[account(
+        mut,
+        seeds = [b"pod_meta", creator.key().as_ref()],
+        bump
+    )]
+    pub pod_meta: Account<'info, PodMeta>,
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", creator.key().as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump
+    )]
+    pub pod_meta_page: Option<Account<'info, PodMetaPage>>,
+    #[account(
+        mut,
+        seeds = [b"pod", creator.key().as_ref(), &id.to_le_bytes()],
+        bump,
+        close = creator,
+        constraint = pod.state.is_final() @ OridionError::PodCloseError,
+    )]
+    pub pod: Account<'info, Pod>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump,
+        close = creator
+    )]
+    pub pod_legs: Option<Account<'info, PodLegs>>,
+    #[account(
+        mut,
+        seeds = [b"pod_note", pod.key().as_ref()],
+        bump = pod_note.bump,
+        close = creator
+    )]
+    pub pod_note: Option<Account<'info, PodNote>>,
+    #[account(
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+
+// Creator closes the last overflow page of their pod meta
+#[derive(Accounts)]
+pub struct CloseMetaPage<'info> {
+    #[account(
+        mut,
+        seeds = [b"pod_meta", creator.key().as_ref()],
+        bump
+    )]
+    pub pod_meta: Account<'info, PodMeta>,
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", creator.key().as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump,
+        close = creator
+    )]
+    pub pod_meta_page: Account<'info, PodMetaPage>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+
+// Creator closes an empty pod meta
+#[derive(Accounts)]
+pub struct ClosePodMeta<'info> {
+    #[account(
+        mut,
+        seeds = [b"pod_meta", creator.key().as_ref()],
+        bump,
+        close = creator
+    )]
+    pub pod_meta: Account<'info, PodMeta>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+
 // Emergency land by creator
 #[derive(Accounts)]
-#[instruction(id: u16)]
+#[instruction(id: u64)]
 pub struct EmergencyLandByCreator<'info> {
     #[account(
+        mut,
         seeds = [b"pod_meta", creator.key().as_ref()],
         bump
     )]
     pub pod_meta: Account<'info, PodMeta>,
+    // Page holding the pod when it overflowed the pod meta
+    #[account(
+        mut,
+        seeds = [b"pod_meta_page", creator.key().as_ref(), &pod_meta_page.page.to_le_bytes()],
+        bump = pod_meta_page.bump
+    )]
+    pub pod_meta_page: Option<Account<'info, PodMetaPage>>,
     #[account(
         mut,
         seeds = [b"pod", creator.key().as_ref(), &id.to_le_bytes()],
@@ -311,15 +499,90 @@ pub struct EmergencyLandByCreator<'info> {
         close = creator
     )]
     pub pod: Account<'info, Pod>,
+    #[account(
+        mut,
+        seeds = [b"pod_legs", pod.key().as_ref()],
+        bump = pod_legs.bump,
+        close = creator
+    )]
+    pub pod_legs: Option<Account<'info, PodLegs>>,
+    #[account(
+        mut,
+        seeds = [b"pod_note", pod.key().as_ref()],
+        bump = pod_note.bump,
+        close = creator
+    )]
+    pub pod_note: Option<Account<'info, PodNote>>,
+
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
 
     #[account(mut)]
     pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
 
     /// The creator is the signer who originally derived the pod PDA.
     pub creator: Signer<'info>,
 
     #[account(mut)]
     pub destination: SystemAccount<'info>,
+    /// CHECK: Denylist PDA of the destination. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", destination.key().as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
+}
+
+
+// Emergency land of a private pod by its owner. Rent returns to the wallet that paid it.
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct EmergencyLandPrivate<'info> {
+    #[account(
+        mut,
+        seeds = [b"pod_c", commitment.as_ref()],
+        bump,
+        close = rent_to
+    )]
+    pub pod: Account<'info, Pod>,
+    #[account(
+        mut,
+        seeds = [b"pod_note", pod.key().as_ref()],
+        bump = pod_note.bump,
+        close = rent_to
+    )]
+    pub pod_note: Option<Account<'info, PodNote>>,
+
+    #[account(
+        mut,
+        seeds = [b"land_book"],
+        bump
+    )]
+    pub book: Account<'info, LandBook>,
+
+    #[account(mut)]
+    pub from_planet: Account<'info, Planet>,
+    #[account(seeds = [UNIVERSE_PDA_SEED], bump = universe.bump)]
+    pub universe: Account<'info, Universe>,
+
+    /// Proves ownership by signature (pod authority) or by opening the commitment.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // Wallet that paid the pod rent, refunded on close like every other pod
+    #[account(
+        mut,
+        constraint = Pod::source_hash(&rent_to.key(), &pod.key()) == pod.rent_payer @ OridionError::RentPayerMismatch
+    )]
+    pub rent_to: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    /// CHECK: Denylist PDA of the destination. Address checked via seeds; an existing entry means denied.
+    #[account(seeds = [b"deny", destination.key().as_ref()], bump)]
+    pub destination_deny: UncheckedAccount<'info>,
 }
 
 
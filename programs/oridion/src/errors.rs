@@ -78,4 +78,108 @@ pub enum OridionError {
     LandBookFull,
     #[msg("Pod cannot be closed")]
     PodCloseError,
+    #[msg("Invalid fee model")]
+    InvalidFeeModel,
+    #[msg("Invalid pod legs")]
+    InvalidLegs,
+    #[msg("Leg amounts do not add up to the pod amount!")]
+    LegSplitMismatch,
+    #[msg("Pod legs account required for split pods")]
+    PodLegsMissing,
+    #[msg("Leg is not ready to land")]
+    LegNotReady,
+    #[msg("Invalid schedule")]
+    InvalidSchedule,
+    #[msg("Next scheduled pod is not due yet")]
+    ScheduleNotDue,
+    #[msg("Schedule has spawned all pods")]
+    ScheduleComplete,
+    #[msg("Delay is shorter than the minimum for this mode")]
+    DelayTooShort,
+    #[msg("Delay is longer than the maximum for this mode")]
+    DelayTooLong,
+    #[msg("Land args do not match the pod")]
+    LandArgsMismatch,
+    #[msg("Planet is not the pod's current location")]
+    PlanetNotPodLocation,
+    #[msg("Planets do not match the scatter meta")]
+    ScatterMetaMismatch,
+    #[msg("Illegal pod state transition")]
+    InvalidPodTransition,
+    #[msg("Pod ownership proof failed")]
+    InvalidPodProof,
+    #[msg("Notes can only be attached in the launch transaction")]
+    NoteNotAllowed,
+    #[msg("Invalid pod note")]
+    InvalidNote,
+    #[msg("Compliance key is not set")]
+    ComplianceKeyUnset,
+    #[msg("Address is on the denylist")]
+    AddressDenied,
+    #[msg("Signer is not the compliance authority")]
+    NotComplianceAuthority,
+    #[msg("Invalid freeze reason or duration")]
+    InvalidFreeze,
+    #[msg("Pod is not frozen")]
+    PodNotFrozen,
+    #[msg("Freeze has not expired")]
+    FreezeActive,
+    #[msg("Freeze has expired")]
+    FreezeExpired,
+    #[msg("Refund wallet did not fund this pod")]
+    SourceMismatch,
+    #[msg("Invalid deposit limits")]
+    InvalidLimits,
+    #[msg("Deposit exceeds the max pod size")]
+    PodTooLarge,
+    #[msg("Deposit exceeds the creator volume limit")]
+    CreatorLimitExceeded,
+    #[msg("Deposit exceeds the daily inflow cap")]
+    DailyInflowExceeded,
+    #[msg("Rent must return to the wallet that paid it")]
+    RentPayerMismatch,
+    #[msg("Pod meta has launched pods, pages or recent volume")]
+    PodMetaNotEmpty,
+    #[msg("Invalid treasury payout limits")]
+    InvalidPayoutLimits,
+    #[msg("Payout exceeds the per-epoch cap")]
+    PayoutCapExceeded,
+    #[msg("Payout is above the timelock threshold and must be proposed")]
+    PayoutNeedsTimelock,
+    #[msg("Payout timelock has not passed")]
+    PayoutLocked,
+    #[msg("Only the treasury guardian or authority can cancel payouts or queued changes")]
+    NotTreasuryGuardian,
+    #[msg("Only the pending treasury authority can accept")]
+    NotPendingAuthority,
+    #[msg("Invalid fee split")]
+    InvalidFeeSplit,
+    #[msg("Fee split recipient account missing")]
+    FeeRecipientMissing,
+    #[msg("Partner share exceeds the maximum")]
+    InvalidPartnerShare,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    #[msg("Star is holding another pod")]
+    StarInUse,
+    #[msg("Star is not held by this pod")]
+    StarNotHeld,
+    #[msg("Invalid batch hop accounts or routes")]
+    InvalidBatch,
+    #[msg("Planet lock was re-taken since the hop was planned")]
+    LockNonceMismatch,
+    #[msg("Invalid lock expiry")]
+    InvalidLockExpiry,
+    #[msg("No treasury change is queued")]
+    NoPendingConfig,
+    #[msg("Creator pod meta required for this pod")]
+    PodMetaMissing,
+    #[msg("Pod has no entry in the pod meta or page")]
+    PodEntryNotFound,
+    #[msg("Creator did not launch this pod")]
+    NotPodCreator,
+    #[msg("Account is not in a legacy layout")]
+    NotLegacyAccount,
+    #[msg("Star name too long")]
+    StarNameTooLong,
 }
\ No newline at end of file
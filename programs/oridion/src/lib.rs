@@ -1,22 +1,32 @@
 #![allow(unexpected_cfgs)]
+// `#[program]` emits Anchor's IDL resize instruction at the crate root, and it calls the
+// deprecated `AccountInfo::realloc`. Lints cannot be scoped to macro output outside the module.
+#![allow(deprecated)]
 
 mod variables;
-mod account_universe;
+pub mod account_universe;
 pub mod account_pod;
-mod account_planet;
-mod account_treasury;
-mod account_star;
+pub mod account_planet;
+pub mod account_treasury;
+pub mod account_star;
 
 mod shared;
-mod errors;
-mod all_accounts;
-mod account_land;
+pub mod errors;
+pub mod all_accounts;
+pub mod account_land;
+pub mod account_schedule;
+mod account_compliance;
+pub mod account_migrate;
 
 use account_pod::*;
 use account_planet::*;
 use account_universe::*;
 use account_treasury::*;
+use account_star::*;
 use account_land::*;
+use account_schedule::*;
+use account_compliance::*;
+use account_migrate::*;
 use all_accounts::*;
 use errors::*;
 use shared::*;
@@ -43,11 +53,9 @@ security_txt! {
 pub mod oridion {
     use super::*;
     use anchor_lang::solana_program::system_instruction::transfer;
-    use anchor_lang::solana_program::system_instruction::create_account;
     use anchor_lang::solana_program::instruction::Instruction;
     use super::sp::program::{invoke_signed};
     use crate::account_land::{InitLandBook, LandBook};
-    use crate::account_star::{Star, StarMeta};
 
     /// UNIVERSE
     pub fn bang(ctx: Context<BigBang>) -> Result<()> {
@@ -58,21 +66,157 @@ pub mod oridion {
         universe.bump = ctx.bumps.universe; // store bump seed in `Counter` account
         universe.created = clock.unix_timestamp;
         universe.last_updated = clock.unix_timestamp; //must set this here as well for random comet id
-        universe.fee = 3000000; //base fee Lamports (0.03 SOL)
-        universe.increment = 100000; // Increment per additional (start)
+        // base fee 3000000 Lamports (0.03 SOL), increment per Delay mode hop 100000
+        universe.fees = Universe::flat_fees(3000000, 100000);
+        universe.delays = Universe::default_delays();
         Ok(())
     }
 
-    /// UPDATE FEE
-    pub fn configure(ctx: Context<UpdateUniverse>, fee: u32, increment: u32) -> Result<()> {
+    /// UPDATE FEE - Sets the fee model for a single mode (1 Delay, 2 Instant, 3 Manual)
+    pub fn configure(ctx: Context<UpdateUniverse>, mode: u8, model: FeeModel) -> Result<()> {
         let clock: Clock = Clock::get()?;
         let universe: &mut Account<Universe> = &mut ctx.accounts.universe;
+        model.validate()?;
+        require!((1..=3).contains(&mode), OridionError::InvalidMode);
         universe.last_updated = clock.unix_timestamp; //must set this here as well for random comet id
-        universe.fee = fee as u64; //Lamports
-        universe.increment = increment as u64; //Lamports
+        universe.fees[(mode - 1) as usize] = model;
         Ok(())
     }
 
+    /// UPDATE DELAY - Sets the min/max delay for a single mode (1 Delay, 2 Instant, 3 Manual)
+    pub fn configure_delay(ctx: Context<UpdateUniverse>, mode: u8, bounds: DelayBounds) -> Result<()> {
+        let clock: Clock = Clock::get()?;
+        let universe: &mut Account<Universe> = &mut ctx.accounts.universe;
+        require!((1..=3).contains(&mode), OridionError::InvalidMode);
+        bounds.validate(mode)?;
+        universe.last_updated = clock.unix_timestamp;
+        universe.delays[(mode - 1) as usize] = bounds;
+        Ok(())
+    }
+
+    /// UPDATE COMPLIANCE KEY - x25519 public key every pod disclosure is also encrypted to
+    pub fn set_compliance_key(ctx: Context<UpdateUniverse>, key: [u8; 32]) -> Result<()> {
+        let clock: Clock = Clock::get()?;
+        let universe: &mut Account<Universe> = &mut ctx.accounts.universe;
+        require!(nonzero_32(&key), OridionError::ComplianceKeyUnset);
+        universe.last_updated = clock.unix_timestamp;
+        universe.compliance_key = key;
+        Ok(())
+    }
+
+    /// UPDATE COMPLIANCE AUTHORITY - Wallet that manages the denylist
+    pub fn set_compliance_authority(ctx: Context<UpdateUniverse>, authority: Pubkey) -> Result<()> {
+        let clock: Clock = Clock::get()?;
+        let universe: &mut Account<Universe> = &mut ctx.accounts.universe;
+        universe.last_updated = clock.unix_timestamp;
+        universe.compliance_authority = authority;
+        Ok(())
+    }
+
+    /// DENYLIST - Compliance screens `address` out of launch and land
+    pub fn deny_address(ctx: Context<DenyAddress>, address: Pubkey, reason: u8) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let entry = &mut ctx.accounts.entry;
+        entry.address = address;
+        entry.reason = reason;
+        entry.added_at = now;
+        entry.bump = ctx.bumps.entry;
+        emit!(DenylistUpdated { address, denied: true, reason, at: now });
+        Ok(())
+    }
+
+    /// DENYLIST - Compliance lifts a denial. Rent returns to the authority.
+    pub fn allow_address(ctx: Context<AllowAddress>, address: Pubkey) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        emit!(DenylistUpdated { address, denied: false, reason: ctx.accounts.entry.reason, at: now });
+        Ok(())
+    }
+
+    /// COMPLIANCE FREEZE - Holds a pod for review. Hops and landing are blocked until
+    /// the freeze is released or the pod is refunded. Pods mid star/scatter hop must land on a planet first.
+    pub fn freeze_pod(ctx: Context<FreezePod>, reason: u8, duration: u32) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        require!(reason != 0, OridionError::InvalidFreeze);
+        require!(duration > 0 && duration <= MAX_FREEZE_SECONDS, OridionError::InvalidFreeze);
+
+        let pod = &mut ctx.accounts.pod;
+        let until = now + duration as i64;
+        pod.freeze(reason, until)?;
+        pod.record(ActivityAction::Freeze, &clock);
+        emit!(PodFrozen { pod: pod.key(), reason, until });
+        Ok(())
+    }
+
+    /// COMPLIANCE RELEASE - Returns a frozen pod to the state it was frozen from
+    pub fn release_pod(ctx: Context<ReleasePod>) -> Result<()> {
+        let clock = Clock::get()?;
+        let by_compliance = ctx.accounts.signer.key() == ctx.accounts.universe.compliance_authority;
+        let pod = &mut ctx.accounts.pod;
+        pod.release(by_compliance, clock.unix_timestamp)?;
+        pod.record(ActivityAction::Release, &clock);
+        emit!(PodReleased { pod: pod.key(), at: clock.unix_timestamp });
+        Ok(())
+    }
+
+    /// COMPLIANCE REFUND - Returns a frozen pod's lamports to the wallet that funded it.
+    /// The pod stays open as Cancelled with its reason code until `reclaim`.
+    pub fn refund_pod(ctx: Context<RefundPod>) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        let pod = &mut ctx.accounts.pod;
+        let from = &mut ctx.accounts.from_planet;
+        require!(now < pod.frozen_until, OridionError::FreezeExpired);
+        require!(
+            Pod::source_hash(&ctx.accounts.source.key(), &pod.key()) == pod.source,
+            OridionError::SourceMismatch
+        );
+        from.enter(pod.key(), None, now, ctx.accounts.universe.lock_expiry())?;
+
+        let refund_lamports = pod.lamports;
+        // No ticket of this pod may be delivered afterwards.
+        let key = pod.key();
+        ctx.accounts.book.land_pod(&key, pod, ctx.accounts.pod_legs.as_deref(), PodState::Cancelled, now)?;
+        pod.record(ActivityAction::Refund, &clock);
+
+        require!(from.get_lamports() >= refund_lamports, OridionError::PlanetNotEnoughFundsError);
+        ctx.accounts.source.add_lamports(refund_lamports)?;
+        from.sub_lamports(refund_lamports)?;
+        release_planet_lock(from)?;
+
+        emit!(PodRefunded { pod: pod.key(), reason: pod.freeze_reason, lamports: refund_lamports, at: now });
+        Ok(())
+    }
+
+    /// UPDATE LIMITS - Max pod size, per-creator rolling volume and global daily inflow
+    pub fn configure_limits(ctx: Context<UpdateUniverse>, limits: DepositLimits) -> Result<()> {
+        let clock: Clock = Clock::get()?;
+        let universe: &mut Account<Universe> = &mut ctx.accounts.universe;
+        limits.validate()?;
+        universe.last_updated = clock.unix_timestamp;
+        universe.limits = limits;
+        Ok(())
+    }
+
+    /// LOCK EXPIRY - Seconds a planet lock stays live. Locks expire once they are that old.
+    pub fn configure_lock_expiry(ctx: Context<UpdateUniverse>, seconds: u32) -> Result<()> {
+        require!(
+            seconds > 0 && seconds <= MAX_LOCK_EXPIRE_SECONDS,
+            OridionError::InvalidLockExpiry
+        );
+        let universe = &mut ctx.accounts.universe;
+        universe.last_updated = Clock::get()?.unix_timestamp;
+        universe.lock_expiry = seconds;
+        Ok(())
+    }
+
+    /// QUOTE - Read-only. Runs the same validations and fee math as `launch`
+    /// and returns the result as return data so clients can simulate it.
+    pub fn quote(ctx: Context<QuoteFee>, args: PodArgs) -> Result<PodQuote> {
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.universe.plan_launch(&args, now)
+    }
+
     /// LOCK UNIVERSE
     pub fn seal(ctx: Context<UpdateUniverse>) -> Result<()> {
         let universe = &mut ctx.accounts.universe;
@@ -98,6 +242,19 @@ pub mod oridion {
         Ok(())
     }
 
+    /// CREATE STAR - Adds a star to the reusable pool
+    pub fn register_star(ctx: Context<CreateStar>, name: String) -> Result<()> {
+        require!(name.len() <= MAX_STAR_NAME_LENGTH, OridionError::StarNameTooLong);
+        let star = &mut ctx.accounts.star;
+        star.bump = ctx.bumps.star;
+        Ok(())
+    }
+
+    /// DELETE STAR - Removes a free star from the pool
+    pub fn retire_star(_ctx: Context<DeleteStar>) -> Result<()> {
+        Ok(())
+    }
+
     /// DELETE PLANET
     pub fn retire_node(ctx: Context<DeletePlanet>) -> Result<()> {
         let planet: &mut Account<Planet> = &mut ctx.accounts.planet;
@@ -124,16 +281,16 @@ pub mod oridion {
 
     /// WITHDRAW FROM TREASURY
     pub fn payout(ctx: Context<WithdrawFromTreasury>, amount: u64, ) -> Result<()> {
-        // Safety: Check if Treasury has enough
-        let treasury_balance = **ctx.accounts.treasury.to_account_info().lamports.borrow();
-        require!(
-            treasury_balance >= amount,
-            OridionError::InsufficientTreasuryBalance
-        );
+        // Safety: Treasury keeps its rent, direct payouts are capped per epoch
+        let treasury = &mut ctx.accounts.treasury;
+        let info = treasury.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(info.data_len());
+        let epoch = Clock::get()?.epoch;
+        treasury.check_payout(amount, info.lamports(), rent_floor, epoch, false)?;
 
         // Move lamports manually
-        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? -= amount;
-        **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+        treasury.sub_lamports(amount)?;
+        ctx.accounts.recipient.add_lamports(amount)?;
         msg!(
             "Withdrawn {} lamports from treasury to {}",
             amount,
@@ -142,8 +299,160 @@ pub mod oridion {
         Ok(())
     }
 
+    /// TREASURY LIMITS - Guardian, per-epoch cap and timelock for large payouts.
+    /// Tightening applies at once; relaxing a limit or changing the guardian waits out the timelock delay.
+    pub fn configure_treasury(ctx: Context<ConfigureTreasury>, guardian: Pubkey, limits: PayoutLimits) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let treasury = &mut ctx.accounts.treasury;
+        if !treasury.propose_config(guardian, limits, now)? {
+            emit!(TreasuryConfigQueued { guardian, limits, apply_after: treasury.config_after });
+        }
+        Ok(())
+    }
+
+    /// TREASURY LIMITS - Applies queued limits, guardian and fee split once their delay has passed
+    pub fn apply_treasury_config(ctx: Context<ConfigureTreasury>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.treasury.apply_config(now)
+    }
+
+    /// TREASURY LIMITS - Guardian or authority drops every queued change, including an authority transfer
+    pub fn cancel_treasury_config(ctx: Context<CancelTreasuryConfig>) -> Result<()> {
+        ctx.accounts.treasury.cancel_config()?;
+        emit!(TreasuryConfigCancelled { by: ctx.accounts.signer.key() });
+        Ok(())
+    }
+
+    /// PROPOSE PAYOUT - Queues a payout that can execute after the timelock delay
+    pub fn propose_payout(ctx: Context<ProposePayout>, amount: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let treasury = &mut ctx.accounts.treasury;
+        let payout = &mut ctx.accounts.payout;
+        payout.id = treasury.next_proposal;
+        payout.recipient = ctx.accounts.recipient.key();
+        payout.amount = amount;
+        payout.execute_after = now + treasury.limits.timelock_delay as i64;
+        payout.bump = ctx.bumps.payout;
+        treasury.next_proposal += 1;
+        emit!(PayoutProposed {
+            id: payout.id,
+            recipient: payout.recipient,
+            amount,
+            execute_after: payout.execute_after,
+        });
+        Ok(())
+    }
+
+    /// EXECUTE PAYOUT - Pays a queued proposal once its delay has passed
+    pub fn execute_payout(ctx: Context<ExecutePayout>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let payout = &ctx.accounts.payout;
+        require!(now >= payout.execute_after, OridionError::PayoutLocked);
+
+        let treasury = &mut ctx.accounts.treasury;
+        let info = treasury.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(info.data_len());
+        treasury.check_payout(payout.amount, info.lamports(), rent_floor, Clock::get()?.epoch, true)?;
+
+        treasury.sub_lamports(payout.amount)?;
+        ctx.accounts.recipient.add_lamports(payout.amount)?;
+        emit!(PayoutExecuted { id: payout.id, recipient: payout.recipient, amount: payout.amount });
+        Ok(())
+    }
+
+    /// TREASURY AUTHORITY - Proposes a new authority, which can accept after the timelock delay.
+    /// The guardian can cancel it until then.
+    pub fn transfer_treasury(ctx: Context<ConfigureTreasury>, new_authority: Pubkey) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.treasury.propose_authority(new_authority, now)
+    }
+
+    /// TREASURY AUTHORITY - Pending authority takes over
+    pub fn accept_treasury(ctx: Context<AcceptTreasury>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let treasury = &mut ctx.accounts.treasury;
+        let previous = treasury.accept_authority(now)?;
+        emit!(TreasuryAuthorityChanged { previous, authority: treasury.authority });
+        Ok(())
+    }
+
+    /// FEE SPLIT - Shares of collected fees for the operator, insurance fund and partners.
+    /// Lowering shares applies at once; raising one or changing a recipient is queued like
+    /// `configure_treasury` and applied by `apply_treasury_config`.
+    pub fn configure_fee_split(ctx: Context<ConfigureTreasury>, split: FeeSplit) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let treasury = &mut ctx.accounts.treasury;
+        if !treasury.propose_split(split, now)? {
+            emit!(FeeSplitQueued { split, apply_after: treasury.config_after });
+        }
+        Ok(())
+    }
+
+    /// DISTRIBUTE - Sweeps the split shares of fees collected since the last sweep.
+    /// The treasury keeps the remainder. Anyone can call it.
+    pub fn distribute(ctx: Context<Distribute>) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        let fees = treasury.undistributed;
+        let [operator, insurance, partner] = treasury.split.shares(fees);
+        let recipients = [
+            (operator, ctx.accounts.operator.as_ref()),
+            (insurance, ctx.accounts.insurance.as_ref()),
+            (partner, ctx.accounts.partner.as_ref()),
+        ];
+        for (share, recipient) in recipients {
+            if share == 0 {
+                continue;
+            }
+            let recipient = recipient.ok_or(OridionError::FeeRecipientMissing)?;
+            treasury.sub_lamports(share)?;
+            recipient.add_lamports(share)?;
+        }
+        treasury.undistributed = 0;
+        emit!(FeesDistributed { fees, operator, insurance, partner });
+        Ok(())
+    }
+
+    /// PARTNERS - Treasury authority registers a partner wallet and its share of launch fees
+    pub fn register_partner(ctx: Context<RegisterPartner>, wallet: Pubkey, bps: u16) -> Result<()> {
+        require!(bps <= MAX_PARTNER_BPS, OridionError::InvalidPartnerShare);
+        let partner = &mut ctx.accounts.partner;
+        partner.wallet = wallet;
+        partner.bps = bps;
+        partner.active = true;
+        partner.bump = ctx.bumps.partner;
+        Ok(())
+    }
+
+    /// PARTNERS - Changes a partner's share or pauses it. Accrued fees stay claimable.
+    pub fn update_partner(ctx: Context<UpdatePartner>, bps: u16, active: bool) -> Result<()> {
+        require!(bps <= MAX_PARTNER_BPS, OridionError::InvalidPartnerShare);
+        let partner = &mut ctx.accounts.partner;
+        partner.bps = bps;
+        partner.active = active;
+        Ok(())
+    }
+
+    /// PARTNERS - Partner wallet withdraws its accrued fees
+    pub fn claim_partner(ctx: Context<ClaimPartner>) -> Result<()> {
+        let partner = &mut ctx.accounts.partner;
+        let amount = partner.accrued;
+        require!(amount > 0, OridionError::NothingToClaim);
+        partner.accrued = 0;
+        partner.sub_lamports(amount)?;
+        ctx.accounts.wallet.add_lamports(amount)?;
+        emit!(PartnerClaimed { wallet: partner.wallet, amount });
+        Ok(())
+    }
+
+    /// CANCEL PAYOUT - Guardian or authority drops a queued proposal
+    pub fn cancel_payout(ctx: Context<CancelPayout>) -> Result<()> {
+        emit!(PayoutCancelled { id: ctx.accounts.payout.id, by: ctx.accounts.signer.key() });
+        Ok(())
+    }
+
 
     #[derive(AnchorSerialize, AnchorDeserialize)]
+    #[derive(Clone, Copy)]
     pub struct PodArgs {
         pub l: u64, //Lamports
         pub m: u8, //Mode
@@ -153,6 +462,7 @@ pub mod oridion {
         pub de2: [u8; 16], // Destination
         pub au1: [u8; 16], // Authority
         pub au2: [u8; 16], // Authority
+        pub s: bool, // Sealed: de1/de2 hold a destination commitment, revealed at land time
     }
 
 
@@ -164,34 +474,15 @@ pub mod oridion {
     /// destination address at any time. This is not safe for the user. User should know
     /// that Oridion cannot alter their deposit or destination.
     ///-------------------------------------------------------------------///
-    pub fn launch(ctx: Context<CreatePod>, id: u16, args: PodArgs) -> Result<()> {
+    pub fn launch(ctx: Context<CreatePod>, args: PodArgs) -> Result<()> {
 
         // -------------------------------------------------//
         // ARGUMENT VALIDATIONS
-        //Prevent 0 amount or dust attacks (500 lamports)
-        require!(args.l > 500, OridionError::InvalidDepositAmount);
-
-        //1 Delay, 2 Instant, 3 Manual
-        require!(args.m <= 3, OridionError::InvalidMode);
-
-        // Check: length must be exactly 6 characters
-        // Basic validity checks
-        require!(nonzero_32(&args.p), OridionError::InvalidPasscode);
-
         let clock: Clock = Clock::get()?;
         let now = clock.unix_timestamp;
-
-        //Set the landing timestamp from delay.
-        //We no longer pass the landing timestamp because of timezone/vpn client side issues.
-        let land_at = now + args.d as i64;
-
-        // If delay mode, require that the landing time is after now.
-        if args.m == 1 {
-            require!(land_at > now,OridionError::InvalidLandingTimestamp);
-        }
-
-        // Delay cannot be more than 24 hours for now.
-        require!(args.d <= MAX_DELAY_ALLOWED, OridionError::InvalidDelay);
+        let plan = ctx.accounts.universe.plan_launch(&args, now)?;
+        require_not_denied(&ctx.accounts.creator_deny)?;
+        require_not_denied(&ctx.accounts.destination_deny)?;
         // -------------------------------------------------//
 
         // -------------------------------------------------//
@@ -208,20 +499,7 @@ pub mod oridion {
 
         // -------------------------------------------------//
         // FEE CALCULATIONS
-        let universe_account: &Account<Universe> = &ctx.accounts.universe;
-        let hops = (args.d as u64) / 180;
-        let required_fee = match args.m {
-            1 => universe_account.fee + (hops * universe_account.increment), // Delay
-            _ => universe_account.fee,  // Instant
-        };
-        let total_payment = args.l + required_fee;
-
-        // Validate the creator's funds to cover the total cost
-        let creator_balance = ctx.accounts.creator.lamports();
-        require!(
-            creator_balance >= total_payment,
-            OridionError::InsufficientFunds
-        );
+        let required_fee = plan.fee;
         // -------------------------------------------------//
 
 
@@ -245,11 +523,6 @@ pub mod oridion {
         // -------------------------------------------------//
 
 
-        //Set the authority and destination
-        let authority = combine_halves(args.au1,args.au2);
-        let destination = combine_halves(args.de1,args.de2);
-
-
         // -------------------------------------------------//
         // POD META
         let pod_meta = &mut ctx.accounts.pod_meta;
@@ -257,172 +530,439 @@ pub mod oridion {
         if pod_meta.created_at == 0 {
             pod_meta.created_at = Clock::get()?.unix_timestamp;
         }
-        //If pod id is not already found, add it. Prune if over max.
-        if !pod_meta.ids.contains(&id) {
-            pod_meta.ids.push(id);
-            pod_meta.prune_ids();
+        // Allocate the pod id. The pod PDA is already seeded by `next_pod_id`.
+        let id = ctx.accounts.universe.allocate_pod_id()?;
+
+        //Index the pod. Overflow goes to the page passed in; open pods are never evicted.
+        if pod_meta.index.has_room() {
+            pod_meta.index.insert(id)?;
+        } else {
+            let page = ctx.accounts.pod_meta_page.as_mut().ok_or(OridionError::TooManyPods)?;
+            page.index.insert(id)?;
         }
+
+        // Deposit limits
+        ctx.accounts.universe.count_deposit(Some(pod_meta), args.l, now)?;
         // -------------------------------------------------//
 
 
         // -------------------------------------------------//
         // POD & FEE TRANSFER
-        let transfer_deposit: Instruction = transfer(
-            ctx.accounts.creator.key, // Convert Pubkey to bytes and then to Address
-            universe_account.to_account_info().key, // Same process for universe_account
+        fund_launch(
+            &ctx.accounts.creator,
+            &ctx.accounts.universe,
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.planet,
+            &ctx.accounts.system_program,
             args.l,
+            required_fee,
+        )?;
+        if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+            pay_referrer(&mut ctx.accounts.treasury, referrer, required_fee)?;
+        }
+        // -------------------------------------------------//
+
+
+        // -------------------------------------------------//
+        // Save pod data
+        let source = Pod::source_hash(&ctx.accounts.creator.key(), &pod.key());
+        pod.init_launch(id, &args, ctx.accounts.planet.key(), now, plan.land_at, source);
+        pod.rent_payer = source;
+        pod.indexed = true;
+        pod.record(ActivityAction::Launch, &clock);
+
+        msg!(
+            "Pod launched successfully: type={}, from {}",
+            mode_string(args.m),
+            short_creator
         );
+        Ok(())
+    }
 
-        invoke_signed(
-            &transfer_deposit,
-            &[
-                ctx.accounts.creator.to_account_info(),
-                universe_account.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            &[],
+
+    ///-------------------------------------------------------------------///
+    /// LAUNCH PRIVATE POD
+    /// Same as `launch`, but the pod PDA is seeded by a client-chosen commitment
+    /// instead of the creator, and the pod is never indexed. Nothing on-chain ties the
+    /// pod address to the creator's other pods.
+    /// - The creator's pod meta is only required while creator volume limits are on.
+    /// - commitment = hash("ORIDION_POD_COMMIT_V1", secret, owner) with a random secret.
+    /// - The owner later proves ownership in `user_settle_private` with the secret,
+    ///   or by signing as the pod authority.
+    ///-------------------------------------------------------------------///
+    pub fn launch_private(ctx: Context<CreatePrivatePod>, commitment: [u8; 32], args: PodArgs) -> Result<()> {
+        let clock: Clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        let plan = ctx.accounts.universe.plan_launch(&args, now)?;
+        require_not_denied(&ctx.accounts.creator_deny)?;
+        require_not_denied(&ctx.accounts.destination_deny)?;
+        let required_fee = plan.fee;
+
+        // Deposit limits. The pod id is never recorded in the creator's pod meta.
+        let mut pod_meta = ctx.accounts.pod_meta.as_deref_mut();
+        if let Some(meta) = pod_meta.as_mut() {
+            if meta.created_at == 0 {
+                meta.created_at = now;
+            }
+        }
+        ctx.accounts.universe.count_deposit(pod_meta, args.l, now)?;
+
+        fund_launch(
+            &ctx.accounts.creator,
+            &ctx.accounts.universe,
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.planet,
+            &ctx.accounts.system_program,
+            args.l,
+            required_fee,
         )?;
+        if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+            pay_referrer(&mut ctx.accounts.treasury, referrer, required_fee)?;
+        }
+
+        // The pod id only feeds the land ticket; take it from the commitment.
+        let id = u64::from_le_bytes(commitment[..8].try_into().unwrap());
+        let pod = &mut ctx.accounts.pod;
+        let source = Pod::source_hash(&ctx.accounts.creator.key(), &pod.key());
+        pod.init_launch(id, &args, ctx.accounts.planet.key(), now, plan.land_at, source);
+        pod.rent_payer = source;
+        pod.record(ActivityAction::Launch, &clock);
+        Ok(())
+    }
+
+
+    ///-------------------------------------------------------------------///
+    /// ATTACH POD NOTE
+    /// Stores an opaque ciphertext next to a pod, e.g. a sealed destination
+    /// encrypted to the operator. Only allowed in the launch transaction,
+    /// signed by the wallet that funded the pod.
+    ///-------------------------------------------------------------------///
+    pub fn attach_note(ctx: Context<AttachNote>, data: Vec<u8>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pod = &ctx.accounts.pod;
+        pod.require_launching(now)?;
+        require!(!data.is_empty() && data.len() <= MAX_POD_NOTE_LEN, OridionError::InvalidNote);
 
-        let fee_transfer_ix: Instruction = transfer(
+        let note = &mut ctx.accounts.pod_note;
+        note.pod = pod.key();
+        note.data = data;
+        note.bump = ctx.bumps.pod_note;
+        Ok(())
+    }
+
+
+    ///-------------------------------------------------------------------///
+    /// DISCLOSE POD
+    /// Stores the pod's `DisclosureMeta` encrypted twice: to a viewing key chosen
+    /// by the user and to the universe compliance key. Either key holder can
+    /// rebuild the pod's provenance offline; nobody else learns anything.
+    /// - Envelopes are fixed size so they do not leak which fields were set.
+    /// - Only allowed in the launch transaction. Kept after the pod closes.
+    ///-------------------------------------------------------------------///
+    pub fn disclose(ctx: Context<Disclose>, viewer: Vec<u8>, compliance: Vec<u8>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pod = &ctx.accounts.pod;
+        pod.require_launching(now)?;
+        require!(
+            nonzero_32(&ctx.accounts.universe.compliance_key),
+            OridionError::ComplianceKeyUnset
+        );
+        require!(
+            viewer.len() == DISCLOSURE_LEN && compliance.len() == DISCLOSURE_LEN,
+            OridionError::InvalidNote
+        );
+
+        let disclosure = &mut ctx.accounts.pod_disclosure;
+        disclosure.pod = pod.key();
+        disclosure.viewer = viewer;
+        disclosure.compliance = compliance;
+        disclosure.bump = ctx.bumps.pod_disclosure;
+        Ok(())
+    }
+
+
+    #[derive(AnchorSerialize, AnchorDeserialize)]
+    pub struct LegArgs {
+        pub de1: [u8; 16], // Destination
+        pub de2: [u8; 16], // Destination
+        pub k: u8, // Amount kind: 0 lamports, 1 basis points of the pod amount
+        pub v: u64, // Amount value
+        pub d: u32, // Delay from launch
+    }
+
+
+    ///-------------------------------------------------------------------///
+    /// SPLIT POD
+    /// Turns a freshly launched delay pod into up to MAX_POD_LEGS delivery legs.
+    /// - Must be sent by the creator in the same transaction as `launch`.
+    /// - Every leg must land no later than the pod's own land_at, which the fee covered.
+    /// - Rounding dust from basis point legs goes to the last leg.
+    ///-------------------------------------------------------------------///
+    pub fn split(ctx: Context<SplitPod>, _id: u64, legs: Vec<LegArgs>) -> Result<()> {
+        let pod = &mut ctx.accounts.pod;
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+
+        require!(pod.mode == 1, OridionError::InvalidMode);
+        require!(pod.legs == 0 && pod.state == PodState::Launched, OridionError::InvalidLegs);
+        require!(
+            legs.len() >= 2 && legs.len() <= MAX_POD_LEGS,
+            OridionError::InvalidLegs
+        );
+
+        let mut total: u64 = 0;
+        let mut pod_legs = Vec::with_capacity(legs.len());
+        for leg in legs.iter() {
+            let destination = combine_halves(leg.de1, leg.de2);
+            require!(nonzero_32(&destination), OridionError::InvalidDestination);
+
+            let land_at = pod.created_at + leg.d as i64;
+            require!(land_at > now && land_at <= pod.land_at, OridionError::InvalidLandingTimestamp);
+
+            let lamports = match leg.k {
+                0 => leg.v,
+                1 => {
+                    require!(leg.v <= BPS_DENOMINATOR, OridionError::InvalidLegs);
+                    ((pod.lamports as u128 * leg.v as u128) / BPS_DENOMINATOR as u128) as u64
+                }
+                _ => return err!(OridionError::InvalidLegs),
+            };
+            require!(lamports > 0, OridionError::InvalidDepositAmount);
+
+            total = total.checked_add(lamports).ok_or(OridionError::UnusualMathError)?;
+            pod_legs.push(PodLeg { destination, lamports, land_at, status: 0 });
+        }
+
+        // Absorb basis point rounding dust (at most 1 lamport per leg) into the last leg.
+        require!(total <= pod.lamports, OridionError::LegSplitMismatch);
+        let dust = pod.lamports - total;
+        require!(dust < pod_legs.len() as u64, OridionError::LegSplitMismatch);
+        if let Some(last) = pod_legs.last_mut() {
+            last.lamports += dust;
+        }
+
+        pod.legs = pod_legs.len() as u8;
+        pod.record(ActivityAction::Split, &clock);
+        let meta = &mut ctx.accounts.pod_legs;
+        meta.legs = pod_legs;
+        meta.bump = ctx.bumps.pod_legs;
+        Ok(())
+    }
+
+
+    ///-------------------------------------------------------------------///
+    /// OPEN POD META PAGE
+    /// Opens the next overflow page for a creator whose pod meta is full.
+    ///-------------------------------------------------------------------///
+    pub fn open_meta_page(ctx: Context<OpenMetaPage>) -> Result<()> {
+        let pod_meta = &mut ctx.accounts.pod_meta;
+        let page = &mut ctx.accounts.pod_meta_page;
+        page.page = pod_meta.pages;
+        page.bump = ctx.bumps.pod_meta_page;
+        pod_meta.pages = pod_meta.pages
+            .checked_add(1)
+            .ok_or(OridionError::UnusualMathError)?;
+        Ok(())
+    }
+
+
+    #[derive(AnchorSerialize, AnchorDeserialize)]
+    pub struct ScheduleArgs {
+        pub pod: PodArgs, // Args for every spawned pod (mode must be Delay)
+        pub i: u32, // Interval between spawns in seconds
+        pub n: u16, // Number of periods
+    }
+
+
+    ///-------------------------------------------------------------------///
+    /// CREATE SCHEDULE
+    /// Funds `n` delay pods up front (deposit + fee per pod). The crank spawns one
+    /// pod every `i` seconds, starting immediately. Fees are quoted now and only
+    /// sent to the treasury as each pod is spawned.
+    ///-------------------------------------------------------------------///
+    pub fn schedule(ctx: Context<CreateSchedule>, id: u16, args: ScheduleArgs) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(args.pod.m == 1, OridionError::InvalidMode);
+        let plan = ctx.accounts.universe.plan_launch(&args.pod, now)?;
+        require_not_denied(&ctx.accounts.creator_deny)?;
+        require_not_denied(&ctx.accounts.destination_deny)?;
+        require!(
+            args.n > 0 && args.n <= MAX_SCHEDULE_PERIODS,
+            OridionError::InvalidSchedule
+        );
+        require!(args.i >= MIN_SCHEDULE_INTERVAL, OridionError::InvalidSchedule);
+
+        let fee = plan.fee;
+        let total_funding = args.pod.l
+            .checked_add(fee)
+            .and_then(|v| v.checked_mul(args.n as u64))
+            .ok_or(OridionError::UnusualMathError)?;
+        require!(
+            ctx.accounts.creator.lamports() >= total_funding,
+            OridionError::InsufficientFunds
+        );
+
+        // Deposit limits count each pod as it spawns, so an unspawned (or cancelled)
+        // schedule holds no inflow or creator volume.
+        if ctx.accounts.pod_meta.created_at == 0 {
+            ctx.accounts.pod_meta.created_at = now;
+        }
+
+        let fund_ix: Instruction = transfer(
             ctx.accounts.creator.key,
-            ctx.accounts.treasury.key,
-            required_fee,
+            ctx.accounts.schedule.to_account_info().key,
+            total_funding,
         );
         invoke_signed(
-            &fee_transfer_ix,
+            &fund_ix,
             &[
                 ctx.accounts.creator.to_account_info(),
-                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.schedule.to_account_info(),
                 ctx.accounts.system_program.to_account_info(),
             ],
             &[],
         )?;
-        // -------------------------------------------------//
 
+        let schedule = &mut ctx.accounts.schedule;
+        schedule.creator = ctx.accounts.creator.key();
+        schedule.id = id;
+        schedule.lamports = args.pod.l;
+        schedule.fee = fee;
+        schedule.delay = args.pod.d;
+        schedule.interval = args.i;
+        schedule.periods = args.n;
+        schedule.spawned = 0;
+        schedule.created_at = now;
+        schedule.next_spawn_at = now;
+        schedule.passcode_hash = args.pod.p;
+        schedule.destination = combine_halves(args.pod.de1, args.pod.de2);
+        schedule.sealed = args.pod.s;
+        schedule.authority = combine_halves(args.pod.au1, args.pod.au2);
+        schedule.bump = ctx.bumps.schedule;
+        Ok(())
+    }
 
 
-        // -------------------------------------------------//
-        // Save pod data
-        pod.account_type = AccountType::Pod as u8;
-        pod.version = 1;
-        pod.mode = args.m;
-        pod.id = id;
-        pod.created_at = now;
-        pod.last_process_at = now;
-        pod.hops = 1;
-        pod.lamports = args.l;
-        pod.last_process = 0; //0 = launch pod
-        pod.delay = args.d;
-        pod.land_at = land_at;
-        pod.passcode_hash = args.p; // pass code hash: [u8; 32]
-        pod.is_in_transit = 0;
-        pod.destination = destination;
-        pod.authority = authority;
-        pod.location = ctx.accounts.planet.key();
-
-        //Depending on the land_at timestamp, set the next process and hop process timestamp
-        //If land_at is less than 3 minutes away (180), land is the next process. Else, hop.
-        if args.m == 3 {
-            pod.next_process = 0; //0 always
-            pod.next_process_at = 0 // always;
-        } else if (now + 180) > land_at {
-            //Landing is next.
-            pod.next_process = 1; //1 = land
-            pod.next_process_at = land_at;
-        } else {
-            //Hop is next. Set the next hop processing timestamp
-            pod.next_process = 0; //0 = hop
-            pod.next_process_at = now + 180;
-        }
+    /// SPAWN - Crank. Launches the next pod of a schedule once it is due.
+    /// The spawned pod follows the normal delay hop and land lifecycle.
+    pub fn spawn(ctx: Context<SpawnPod>) -> Result<()> {
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+        // A creator denied after funding the schedule spawns no more pods
+        require_not_denied(&ctx.accounts.creator_deny)?;
+        let schedule = &mut ctx.accounts.schedule;
+        let index = schedule.spawn_next(&mut ctx.accounts.pod_meta, &mut ctx.accounts.universe, now)?;
+
+        // Delay bounds were checked when the schedule was funded
+        let args = schedule.pod_args();
+        let land_at = now + args.d as i64;
+        let lamports = schedule.lamports;
+        let fee = schedule.fee;
+
+        // TRANSACTION - Schedule funds to planet, prepaid fee to treasury
+        ctx.accounts.schedule.sub_lamports(lamports + fee)?;
+        ctx.accounts.planet.add_lamports(lamports)?;
+        ctx.accounts.treasury.add_lamports(fee)?;
+        ctx.accounts.treasury.accrue(fee)?;
 
-        // -------------------------------------------------//
-        // Planet bookkeeping - Increment visits
         let planet = &mut ctx.accounts.planet;
         planet.visits += 1;
 
-        // TRANSACTION - From galaxy to planet
-        //msg!("Hopping from galaxy to {}", planet_account.name);
-        planet.add_lamports(args.l)?;
-        ctx.accounts.universe.sub_lamports(args.l)?;
+        let pod = &mut ctx.accounts.pod;
+        let source = Pod::source_hash(&ctx.accounts.schedule.creator, &pod.key());
+        pod.init_launch(index as u64, &args, planet.key(), now, land_at, source);
+        pod.rent_payer = Pod::source_hash(&ctx.accounts.manager.key(), &pod.key());
+        pod.record(ActivityAction::Launch, &clock);
+        Ok(())
+    }
 
-        msg!(
-            "Pod launched successfully: type={}, from {}",
-            mode_string(args.m),
-            short_creator
-        );
+
+    /// CANCEL SCHEDULE - Creator only.
+    /// Closing the schedule refunds every unspawned deposit and fee plus rent.
+    /// Pods that were already spawned continue their normal lifecycle.
+    pub fn cancel_schedule(_ctx: Context<CancelSchedule>, _id: u16) -> Result<()> {
         Ok(())
     }
 
 
-    /// LOCK PLANET - Locks the planet during transaction
-    // If unlocked, lock it - (locked_at == 0 -> Proceeds and sets new lock)
-    // If already locked (locked != 0) but lock time has expired (more than LOCK_EXPIRED_SECONDS),
-    // then clear the lock and relock it. (locked_at != 0 && expired - Clears old lock, sets new one)
-    // If already locked (locked != 0) but has not expired yet, then fail.
-    // Locked_at != 0 && has not expired - Fails with PlanetStillLocked
-    pub fn lock_node(ctx: Context<LockPlanet>) -> Result<()> {
+    /// LOCK PLANET - Reserves the planet for a pod ahead of its hop
+    // Only needed when hops race for a planet; hops without a lock nonce lock and
+    // release the planet within their own instruction.
+    // Unlocked or expired (or already held by this pod) - Sets a new lock and returns its nonce
+    // Held by another pod and not expired yet - Returns 0 and counts the contention
+    pub fn lock_node(ctx: Context<LockPlanet>) -> Result<u64> {
         let planet = &mut ctx.accounts.planet;
         let pod = &ctx.accounts.pod;
         let clock = Clock::get()?;
         let now = clock.unix_timestamp;
-    
-        let is_locked = planet.locked_at != 0;
-        let lock_age = now - planet.locked_at;
-        let is_lock_expired = lock_age > LOCK_EXPIRE_SECONDS;
-    
-        // If the planet is locked, it must be expired to proceed
-        if is_locked {
-            require!(is_lock_expired, OridionError::PlanetStillLocked);
-        }
-    
-        // Set to lock
-        planet.locked_by = pod.key();
-        planet.locked_at = now;
-    
-        Ok(())
+
+        // Only the planet currently holding a hoppable pod can be locked for it
+        pod.require_at(&planet.key())?;
+        require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+
+        let expiry = ctx.accounts.universe.lock_expiry();
+        Ok(planet.try_lock(pod.key(), now, expiry).unwrap_or(0))
     }
 
 
-    /// SCATTER LOCK - Same lock but for scatter hop (3 planets)
-    pub fn fan_lock(ctx: Context<ScatterLockPlanets>) -> Result<()> {
+    /// SCATTER LOCK - Same lock but for scatter hop (3 planets). Returns the lock nonces.
+    /// All three planets are locked or none; a busy planet returns zeros.
+    pub fn fan_lock(ctx: Context<ScatterLockPlanets>) -> Result<[u64; 3]> {
         let pod = &ctx.accounts.pod;
         let now = Clock::get()?.unix_timestamp;
 
+        // Only the planets holding this pod's scatter split can be locked
+        require!(pod.state == PodState::InTransitScatter, OridionError::NotInTransit);
+        ctx.accounts.scatter_meta.require_sources(
+            &pod.location,
+            &[
+                ctx.accounts.planet_1.key(),
+                ctx.accounts.planet_2.key(),
+                ctx.accounts.planet_3.key(),
+            ],
+        )?;
+
         let planets = [
             &mut ctx.accounts.planet_1,
             &mut ctx.accounts.planet_2,
             &mut ctx.accounts.planet_3,
         ];
 
-        for planet in planets {
-            let is_locked = planet.locked_at != 0;
-            let lock_age = now - planet.locked_at;
-            let is_lock_expired = lock_age > LOCK_EXPIRE_SECONDS;
-
-            // If the planet is locked, it must be expired to proceed
-            if is_locked {
-                require!(is_lock_expired, OridionError::PlanetStillLocked);
+        let expiry = ctx.accounts.universe.lock_expiry();
+        let held_by_other = |p: &Planet| p.locked_by != pod.key() && p.lock_live(now, expiry);
+        if planets.iter().any(|p| held_by_other(p)) {
+            for planet in planets {
+                if held_by_other(planet) {
+                    planet.count_contention();
+                }
             }
+            return Ok([0; 3]);
+        }
 
-            // Set lock
-            planet.locked_by = pod.key();
-            planet.locked_at = now;
+        let mut nonces = [0u64; 3];
+        for (planet, nonce) in planets.into_iter().zip(nonces.iter_mut()) {
+            *nonce = planet.acquire_lock(pod.key(), now, expiry)?;
         }
-        Ok(())
+        Ok(nonces)
     }
 
 
-
     /// HOP FROM PLANET TO PLANET
-    pub fn route1(ctx: Context<PlanetHop>) -> Result<()>{
+    /// `lock` is the nonce of a lock taken by `lock_node`; without it the hop locks the planet itself.
+    pub fn route1(ctx: Context<PlanetHop>, lock: Option<u64>) -> Result<()>{
 
         let pod: &mut Account<Pod> = &mut ctx.accounts.pod;
         let from: &mut Account<Planet> = &mut ctx.accounts.from_planet;
         let to: &mut Account<Planet> = &mut ctx.accounts.to_planet;
         let book: &mut Account<LandBook> = &mut ctx.accounts.book;
 
-        // Check planet is unlocked
-        validate_planet_is_usable(from,pod.key())?;
+        // Pods mid star or scatter hop hold no funds on `from`
+        require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+
+        // Check planet holds the pod and lock it for this hop
+        pod.require_at(&from.key())?;
+        from.enter(pod.key(), lock, Clock::get()?.unix_timestamp, ctx.accounts.universe.lock_expiry())?;
 
         // Validate `from` and `to` planets are different
         require!(
@@ -438,7 +978,7 @@ pub mod oridion {
 
         // Update pod with new data
         pod.location = to.key();
-        hop_pod(pod, book)?;
+        hop_pod(pod, book, ctx.accounts.pod_legs.as_mut())?;
 
         //Increment visits
         to.visits += 1;
@@ -454,61 +994,122 @@ pub mod oridion {
 
 
 
+    /// BATCH HOP - Several pods hop planet to planet in one instruction.
+    /// `remaining_accounts` holds `planets` planets followed by the pods; `to[i]` is the
+    /// planet index the i-th pod hops to. Planets only see the netted flow of the batch.
+    pub fn route_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchHop<'info>>,
+        planets: u8,
+        to: Vec<u8>,
+    ) -> Result<()> {
+        let accounts = ctx.remaining_accounts;
+        let planet_count = planets as usize;
+        require!(
+            !to.is_empty() && to.len() <= MAX_BATCH_PODS && accounts.len() == planet_count + to.len(),
+            OridionError::InvalidBatch
+        );
+
+        // Every account appears once and is writable
+        for (i, info) in accounts.iter().enumerate() {
+            require!(info.is_writable, OridionError::InvalidBatch);
+            require!(!accounts[i + 1..].iter().any(|other| other.key == info.key), OridionError::InvalidBatch);
+        }
+        let mut planets = accounts[..planet_count]
+            .iter()
+            .map(Account::<Planet>::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let mut pods = accounts[planet_count..]
+            .iter()
+            .map(Account::<Pod>::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let expiry = ctx.accounts.universe.lock_expiry();
+        let book = &mut ctx.accounts.book;
+        let mut moves = Vec::with_capacity(pods.len());
+        for (pod, &to) in pods.iter_mut().zip(to.iter()) {
+            // Split pods hop on their own so their legs get tickets
+            require!(pod.legs == 0, OridionError::InvalidBatch);
+            require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+
+            // Pod must sit on one of the batch planets, which must be usable
+            let from = planets
+                .iter()
+                .position(|planet| planet.key() == pod.location)
+                .ok_or(OridionError::PlanetNotPodLocation)?;
+            let to = to as usize;
+            require!(to < planets.len(), OridionError::InvalidBatch);
+            require!(from != to, OridionError::HopErrorToAndFromAreSame);
+            planets[from].enter(pod.key(), None, now, expiry)?;
+            release_planet_lock(&mut planets[from])?;
+
+            moves.push(BatchMove { from, to, lamports: pod.lamports });
+            pod.location = planets[to].key();
+            hop_pod(pod, book, None)?;
+            planets[to].visits += 1;
+        }
+
+        // TRANSACTION: One netted transfer per planet
+        let flows = net_flows(planets.len(), &moves)?;
+        for (planet, flow) in planets.iter_mut().zip(flows) {
+            let amount = u64::try_from(flow.unsigned_abs()).map_err(|_| OridionError::UnusualMathError)?;
+            if flow < 0 {
+                require!(planet.get_lamports() >= amount, OridionError::InsufficientFunds);
+                planet.sub_lamports(amount)?;
+            } else if flow > 0 {
+                planet.add_lamports(amount)?;
+            }
+        }
+
+        // Remaining accounts are not persisted by Anchor
+        for planet in &planets {
+            planet.exit(ctx.program_id)?;
+        }
+        for pod in &pods {
+            pod.exit(ctx.program_id)?;
+        }
+        Ok(())
+    }
+
+
+
     /// STAR HOP TWO - START (PLANET -> STAR1|START2)
-    /// The "from" planet must be locked before.
-    pub fn route2_start(
-        ctx: Context<StarHopTwoStart>,
-        _star_one_id: String,
-        _star_two_id: String
-    ) -> Result<()>{
+    /// Stars come from the registered pool. `lock` as in `route1`.
+    pub fn route2_start(ctx: Context<StarHopTwoStart>, lock: Option<u64>) -> Result<()>{
 
         let pod: &mut Account<Pod> = &mut ctx.accounts.pod;
-
-        let manager: &Signer = &ctx.accounts.manager;
-        let star1: &mut Account<Star> = &mut ctx.accounts.star_one;
-        let star2: &mut Account<Star> = &mut ctx.accounts.star_two;
         let from: &mut Account<Planet> = &mut ctx.accounts.from_planet;
 
 
         // IMPORTANT VALIDATION:
-        // Planet must be usable. Stars cannot be the same. Pod cannot be in transit.
-        validate_planet_is_usable(from,pod.key())?;
-        require!(pod.is_in_transit == 0, OridionError::InTransit);
-        require!(star1.key() != star2.key(), OridionError::HopErrorStarsMustBeUnique);
+        // Pod must be able to start a hop. Planet must hold the pod and be lockable. Stars cannot be the same.
+        require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+        let clock = Clock::get()?;
+        pod.require_at(&from.key())?;
+        from.enter(pod.key(), lock, clock.unix_timestamp, ctx.accounts.universe.lock_expiry())?;
+        require_unique_stars(&[ctx.accounts.star_one.key(), ctx.accounts.star_two.key()])?;
 
         //Set immediately after validations
-        pod.is_in_transit = 1;
-        pod.last_process_at = Clock::get()?.unix_timestamp;
+        pod.transition(PodState::InTransitStar)?;
+        pod.last_process_at = clock.unix_timestamp;
+        pod.record(ActivityAction::Star2, &clock);
 
         let percent: u8 = get_random_percent();
         let star_one_amount: u64 = (percent as u64 * pod.lamports) / 100;
         let star_two_amount: u64 = pod.lamports - star_one_amount;
-        //msg!("Hopping to star 1: {}", star_one_amount.to_string());
-        //msg!("Hopping to Star 2: {}", star_two_amount.to_string());
 
         //Make sure the amounts are equal to the pod accounts set lamports amount
         require!(star_one_amount + star_two_amount == pod.lamports, OridionError::StarHopCalculationError);
 
-        //Set star key here - must do to prevent errors
-        let star1_key = star1.key();
-        let star2_key = star2.key();
-
-        //Set amounts to accounts
-        star1.amount = star_one_amount;
-        star2.amount = star_two_amount;
-        star1.manager = *manager.key;
-        star2.manager = *manager.key;
+        //Hold the stars for this pod until the hop ends
+        ctx.accounts.star_one.hold(pod.key(), star_one_amount, clock.unix_timestamp)?;
+        ctx.accounts.star_two.hold(pod.key(), star_two_amount, clock.unix_timestamp)?;
 
         // TRANSACTION - Transfer from planet to star one and two
         ctx.accounts.star_one.add_lamports(star_one_amount)?;
         ctx.accounts.star_two.add_lamports(star_two_amount)?;
         from.sub_lamports(pod.lamports)?;
 
-        // STORE META
-        let meta = &mut ctx.accounts.star_meta;
-        meta.to_pdas = [star1_key, star2_key, Pubkey::default(), ];
-        meta.bump = ctx.bumps.star_meta;
-
         //Release planet lock
         release_planet_lock(from)?;
         Ok(())
@@ -522,80 +1123,55 @@ pub mod oridion {
         let pod: &mut Account<Pod> = &mut ctx.accounts.pod;
         let book: &mut Account<LandBook> = &mut ctx.accounts.book;
 
-        require!(pod.is_in_transit == 1, OridionError::NotInTransit);
-        pod.is_in_transit = 0;
-
-        let to: &mut Account<Planet> = &mut ctx.accounts.to_planet;
-        //let to_planet_name: String = to.name.to_owned();
-        let star1: &mut Account<Star> = &mut ctx.accounts.star_one;
-        let star2: &mut Account<Star> = &mut ctx.accounts.star_two;
-        let star_one_amount: u64 = star1.amount.clone();
-        let star_two_amount: u64 = star2.amount.clone();
+        require!(pod.state == PodState::InTransitStar, OridionError::NotInTransit);
+        require_unique_stars(&[ctx.accounts.star_one.key(), ctx.accounts.star_two.key()])?;
 
+        // Stars must be held by this pod and carry all of its lamports
+        let star_one_amount: u64 = ctx.accounts.star_one.release(&pod.key())?;
+        let star_two_amount: u64 = ctx.accounts.star_two.release(&pod.key())?;
         require!(star_one_amount + star_two_amount == pod.lamports, OridionError::StarHopCalculationError);
 
+        let to: &mut Account<Planet> = &mut ctx.accounts.to_planet;
+
         // Update pod with new data
         pod.location = to.key();
-        hop_pod(pod,book)?;
+        end_transit_hop(pod, book, ctx.accounts.pod_legs.as_mut())?;
 
-         //Clear our star amount
-        star1.amount = 0;
-        star2.amount = 0;
-     
         //Increment planet visit
         to.visits += 1;
 
         // TRANSACTIONS
-        // Transaction from stars one and two to the destination planet
-        let total_lamports: u64 = star_one_amount + star_two_amount;
+        // Transaction from stars one and two to the destination planet. Star rent stays.
         ctx.accounts.star_one.sub_lamports(star_one_amount)?;
         ctx.accounts.star_two.sub_lamports(star_two_amount)?;
-        ctx.accounts.to_planet.add_lamports(total_lamports)?;
-
-        // EXPLODE STARS - Transfer out remaining lamports
-        let star_one_remaining_lamports = ctx.accounts.star_one.get_lamports();
-        let star_two_remaining_lamports = ctx.accounts.star_two.get_lamports();
-
-        if star_one_remaining_lamports > 0 {
-            ctx.accounts.manager.add_lamports(star_one_remaining_lamports)?;
-            ctx.accounts.star_one.sub_lamports(star_one_remaining_lamports)?;
-        }
-        if star_two_remaining_lamports > 0 {
-            ctx.accounts.manager.add_lamports(star_two_remaining_lamports)?;
-            ctx.accounts.star_two.sub_lamports(star_two_remaining_lamports)?;
-        }
-
+        ctx.accounts.to_planet.add_lamports(pod.lamports)?;
         Ok(())
     }
 
 
     /// STAR HOP THREE - START (PLANET -> STAR1|START2|STAR3)
-    pub fn route3_start(
-        ctx: Context<StarHopThreeStart>,
-        _star_one_id: String,
-        _star_two_id: String,
-        _star_three_id: String
-    ) -> Result<()>{
+    /// `lock` as in `route1`.
+    pub fn route3_start(ctx: Context<StarHopThreeStart>, lock: Option<u64>) -> Result<()>{
 
         let pod: &mut Account<Pod> = &mut ctx.accounts.pod;
         let from: &mut Account<Planet> = &mut ctx.accounts.from_planet;
-        let manager: &Signer = &ctx.accounts.manager;
-
-        let star1: &mut Account<Star> = &mut ctx.accounts.star_one;
-        let star2: &mut Account<Star> = &mut ctx.accounts.star_two;
-        let star3: &mut Account<Star> = &mut ctx.accounts.star_three;
 
         // IMPORTANT VALIDATION:
-        // Validate the planet is locked. Planet cannot be in transit. Stars cannot be the same
-        validate_planet_locked_by_pod(from,pod.key())?;
-        require!(pod.is_in_transit == 0, OridionError::InTransit);
-        require!(star1.key() != star2.key(), OridionError::HopErrorStarsMustBeUnique);
-        require!(star2.key() != star3.key(), OridionError::HopErrorStarsMustBeUnique);
-        require!(star1.key() != star3.key(), OridionError::HopErrorStarsMustBeUnique);
+        // Pod must be able to start a hop. Validate the planet holds the pod and lock it. Stars cannot be the same
+        require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+        let clock = Clock::get()?;
+        pod.require_at(&from.key())?;
+        from.enter(pod.key(), lock, clock.unix_timestamp, ctx.accounts.universe.lock_expiry())?;
+        require_unique_stars(&[
+            ctx.accounts.star_one.key(),
+            ctx.accounts.star_two.key(),
+            ctx.accounts.star_three.key(),
+        ])?;
 
         // Set in transit
-        pod.is_in_transit = 1;
-        pod.last_process_at = Clock::get()?.unix_timestamp;
+        pod.transition(PodState::InTransitStar)?;
+        pod.last_process_at = clock.unix_timestamp;
+        pod.record(ActivityAction::Star3, &clock);
 
 
         let (first_split_percent, second_split_percent) = (get_random_percent(), get_random_percent());
@@ -627,7 +1203,7 @@ pub mod oridion {
         //
         // This guarantees that the total amount sent to stars always equals
         // the original deposit amount, preserving value integrity.
-        let diff = pod.lamports.checked_sub(computed_total).unwrap_or(0);
+        let diff = pod.lamports.saturating_sub(computed_total);
         star_one_amount = star_one_amount.saturating_add(diff); // Dust absorbed by star_one
 
         // Final sanity check
@@ -636,80 +1212,18 @@ pub mod oridion {
             OridionError::StarHopCalculationError
         );
 
-        let star1_key = star1.key();
-        let star2_key = star2.key();
-        let star3_key = star3.key();
-
-        star1.amount = star_one_amount;
-        star2.amount = star_two_amount;
-        star3.amount = star_three_amount;
-        star1.manager = *manager.key;
-        star2.manager = *manager.key;
-        star3.manager = *manager.key;
+        //Hold the stars for this pod until the hop ends
+        let now = clock.unix_timestamp;
+        ctx.accounts.star_one.hold(pod.key(), star_one_amount, now)?;
+        ctx.accounts.star_two.hold(pod.key(), star_two_amount, now)?;
+        ctx.accounts.star_three.hold(pod.key(), star_three_amount, now)?;
 
-        // Transfer from planet to star one and two
+        // Transfer from planet to star one, two and three
         ctx.accounts.star_one.add_lamports(star_one_amount)?;
         ctx.accounts.star_two.add_lamports(star_two_amount)?;
         ctx.accounts.star_three.add_lamports(star_three_amount)?;
         from.sub_lamports(pod.lamports)?;
 
-        let rent = Rent::get()?;
-        let space = 8 + StarMeta::INIT_SPACE;
-        let lamports = rent.minimum_balance(space);
-
-        let pod_key = pod.to_account_info().key;
-        let seeds = &[b"star_three", pod_key.as_ref()];
-        let (pda, bump) = Pubkey::find_program_address(seeds, ctx.program_id);
-        let signer_seeds = &[b"star_three", pod_key.as_ref(), &[bump]];
-
-        //Get star meta
-        let star_meta = &mut ctx.accounts.star_meta;
-
-        //star_meta account passed must be the same as the derived here.
-        require!(star_meta.key() == pda,OridionError::InvalidStarMetaPda);
-
-        // Safety check: Make sure the PDA isn't already initialized
-        require!(
-            star_meta.to_account_info().lamports() == 0,
-            OridionError::PdaAlreadyInitialized
-        );
-
-        // Create the star_meta data account
-        let system_program = &ctx.accounts.system_program;
-        invoke_signed(
-            &create_account(
-                manager.key,
-                &pda,
-                lamports,
-                space as u64,
-                ctx.program_id,
-            ),
-            &[
-                manager.to_account_info(),
-                star_meta.to_account_info(),
-                system_program.to_account_info(),
-            ],
-            &[signer_seeds],
-        )?;
-
-
-        // Set the star_meta data
-        let meta = StarMeta {
-            to_pdas: [star1_key, star2_key, star3_key],
-            bump,
-        };
-
-        // OLD WAY
-        //let mut data = star_meta.try_borrow_mut_data()?;
-        // Write discriminator first
-        // let discriminator = StarMeta::discriminator();
-        // data[..8].copy_from_slice(&discriminator);
-        // Then serialize the struct starting after the discriminator
-        //meta.serialize(&mut &mut data[8..])?;
-
-        let mut data = star_meta.try_borrow_mut_data()?;
-        meta.try_serialize(&mut &mut data[..])?;
-
         //Release lock
         release_planet_lock(from)?;
 
@@ -724,80 +1238,74 @@ pub mod oridion {
         let book: &mut Account<LandBook> = &mut ctx.accounts.book;
 
 
-        require!(pod.is_in_transit == 1, OridionError::NotInTransit);
-        pod.is_in_transit = 0;
-
-        let to: &mut Account<Planet> = &mut ctx.accounts.to_planet;
-        let star1: &mut Account<Star> = &mut ctx.accounts.star_one;
-        let star2: &mut Account<Star> = &mut ctx.accounts.star_two;
-        let star3: &mut Account<Star> = &mut ctx.accounts.star_three;
-        let star_one_amount: u64 = star1.amount.clone();
-        let star_two_amount: u64 = star2.amount.clone();
-        let star_three_amount: u64 = star3.amount.clone();
+        require!(pod.state == PodState::InTransitStar, OridionError::NotInTransit);
+        require_unique_stars(&[
+            ctx.accounts.star_one.key(),
+            ctx.accounts.star_two.key(),
+            ctx.accounts.star_three.key(),
+        ])?;
 
+        // Stars must be held by this pod and carry all of its lamports
+        let star_one_amount: u64 = ctx.accounts.star_one.release(&pod.key())?;
+        let star_two_amount: u64 = ctx.accounts.star_two.release(&pod.key())?;
+        let star_three_amount: u64 = ctx.accounts.star_three.release(&pod.key())?;
         require!(star_one_amount + star_two_amount + star_three_amount == pod.lamports, OridionError::StarHopCalculationError);
 
+        let to: &mut Account<Planet> = &mut ctx.accounts.to_planet;
+
         // Update pod location
         pod.location = to.key();
-        hop_pod(pod,book)?;
+        end_transit_hop(pod, book, ctx.accounts.pod_legs.as_mut())?;
 
-         //Clear our star amount
-        star1.amount = 0;
-        star2.amount = 0;
-        star3.amount = 0;
-     
         //Increment planet visit
         to.visits += 1;
 
 
         // TRANSACTIONS
-        // Transaction from stars one and two to the destination planet
+        // Transaction from the stars to the destination planet. Star rent stays.
         ctx.accounts.star_one.sub_lamports(star_one_amount)?;
         ctx.accounts.star_two.sub_lamports(star_two_amount)?;
         ctx.accounts.star_three.sub_lamports(star_three_amount)?;
         ctx.accounts.to_planet.add_lamports(pod.lamports)?;
-
-        // EXPLODE STARS - Transfer out remaining lamports
-        // Transfer remaining lamports (dust, rent) from star accounts after transfer.
-        // This ensures complete cleanup and consolidates unused funds back to the manager.
-        for star in [&ctx.accounts.star_one, &ctx.accounts.star_two, &ctx.accounts.star_three] {
-            let remaining = star.get_lamports();
-            ctx.accounts.manager.add_lamports(remaining)?;
-            star.sub_lamports(remaining)?;
-        }
         Ok(())
     }
 
 
     /// SCATTER HOP - START (PLANET -> PLANET1|PLANET2|PLANET3|PLANET4|PLANET5)
-    pub fn fan_start(ctx: Context<ScatterStart>) -> Result<()> {
+    /// `lock` as in `route1`.
+    pub fn fan_start(ctx: Context<ScatterStart>, lock: Option<u64>) -> Result<()> {
         let pod = &mut ctx.accounts.pod;
         let from = &mut ctx.accounts.from_planet;
 
-        // Validate that the "from" planet is locked or pod owns the lock
-        validate_planet_locked_by_pod(from, pod.key())?;
+        // Pod must be able to start a hop
+        require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+
+        // Validate that the "from" planet holds the pod and lock it
+        pod.require_at(&from.key())?;
+        from.enter(pod.key(), lock, Clock::get()?.unix_timestamp, ctx.accounts.universe.lock_expiry())?;
 
-        require!(pod.is_in_transit == 0, OridionError::InTransit);
+        pod.transition(PodState::InTransitScatter)?;
 
         let total = pod.lamports;
         require!(total > 0, OridionError::InvalidDepositAmount);
         require!(from.get_lamports() >= total, OridionError::PlanetNotEnoughFundsError);
 
         // Generate pseudo-random split using block timestamp
-        let now = Clock::get()?.unix_timestamp;
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
         let rng = anchor_lang::solana_program::keccak::hashv(&[&now.to_le_bytes()]);
         let mut splits = [0u64; 3];
         let mut remaining = total;
 
-        for i in 0..2 {
+        for (i, split) in splits.iter_mut().take(2).enumerate() {
             let rand_seed = u64::from_le_bytes([
                 rng.0[i], rng.0[i + 1], rng.0[i + 2], rng.0[i + 3],
                 rng.0[i + 4], rng.0[i + 5], rng.0[i + 6], rng.0[i + 7],
             ]);
             let rand_percent = (rand_seed % 50) + 1;
             let amt = (total * rand_percent) / 100;
-            splits[i] = amt.min(remaining);
-            remaining -= splits[i];
+            *split = amt.min(remaining);
+            remaining -= *split;
         }
 
         splits[2] = remaining;
@@ -817,8 +1325,8 @@ pub mod oridion {
         from.sub_lamports(total)?;
 
         // Distribute to destination planets
-        for i in 0..3 {
-            planets[i].add_lamports(splits[i])?;
+        for (planet, amt) in planets.iter().zip(splits) {
+            planet.add_lamports(amt)?;
         }
 
         // Store meta data
@@ -837,8 +1345,8 @@ pub mod oridion {
         meta.created_at = now;
         meta.bump = ctx.bumps.scatter_meta;
 
-        pod.is_in_transit = 1;
         pod.last_process_at = now;
+        pod.record(ActivityAction::Scatter, &clock);
 
         release_planet_lock(from)?;
 
@@ -847,7 +1355,8 @@ pub mod oridion {
 
 
     /// SCATTER HOP - END (PLANET1|PLANET2|PLANET3|PLANET4|PLANET5 -> PLANET)
-    pub fn fan_end(ctx: Context<ScatterEnd>) -> Result<()> {
+    /// `locks` are the `fan_lock` nonces; without them the planets are locked here.
+    pub fn fan_end(ctx: Context<ScatterEnd>, locks: Option<[u64; 3]>) -> Result<()> {
         let meta = &ctx.accounts.scatter_meta;
         let pod = &mut ctx.accounts.pod;
         let to = &mut ctx.accounts.to_planet;
@@ -855,27 +1364,38 @@ pub mod oridion {
 
         //Validate transmit meta is found and the pod is in transit.
         require!(meta.created_at > 0,OridionError::InvalidScatterMeta);
-        require!(pod.is_in_transit == 1, OridionError::NotInTransit);
+        require!(pod.state == PodState::InTransitScatter, OridionError::NotInTransit);
+
+        // Source planets must be the ones the scatter split went to
+        meta.require_sources(
+            &pod.location,
+            &[
+                ctx.accounts.from_planet_1.key(),
+                ctx.accounts.from_planet_2.key(),
+                ctx.accounts.from_planet_3.key(),
+            ],
+        )?;
 
         //Gather all planets to transfer from
-        let from_planets = [
+        let mut from_planets = [
             &mut ctx.accounts.from_planet_1,
             &mut ctx.accounts.from_planet_2,
             &mut ctx.accounts.from_planet_3,
         ];
 
-        //Validate all planets are usable.
-        for i in 0..3 {
-            validate_planet_is_usable(from_planets[i],pod.key())?;
+        //Lock all planets for this pod
+        let now = Clock::get()?.unix_timestamp;
+        let expiry = ctx.accounts.universe.lock_expiry();
+        for (i, planet) in from_planets.iter_mut().enumerate() {
+            planet.enter(pod.key(), locks.map(|l| l[i]), now, expiry)?;
         }
 
 
         //Tally the total
         let mut total_collected: u64 = 0;
-        for i in 0..3 {
-            let amt = meta.amounts[i];
+        for (planet, amt) in from_planets.iter_mut().zip(meta.amounts) {
             require!(
-                from_planets[i].get_lamports() >= amt,
+                planet.get_lamports() >= amt,
                 OridionError::PlanetNotEnoughFundsError
             );
             total_collected = total_collected
@@ -883,7 +1403,7 @@ pub mod oridion {
                 .ok_or(OridionError::UnusualMathError)?;
 
             //Release echo planet lock
-            release_planet_lock(from_planets[i])?;
+            release_planet_lock(planet)?;
         }
 
         // Check that total matches original pod deposit
@@ -894,17 +1414,14 @@ pub mod oridion {
 
         // Update pod location
         pod.location = to.key();
-        hop_pod(pod,book)?;
+        end_transit_hop(pod, book, ctx.accounts.pod_legs.as_mut())?;
 
         // Perform transfers
-        for i in 0..3 {
-            let amt = meta.amounts[i];
-            from_planets[i].sub_lamports(amt)?;
+        for (planet, amt) in from_planets.iter().zip(meta.amounts) {
+            planet.sub_lamports(amt)?;
             to.add_lamports(amt)?;
         }
 
-        pod.is_in_transit = 0;
-
         Ok(())
     }
 
@@ -912,23 +1429,34 @@ pub mod oridion {
     /// LAND FUNDS TO FINAL DESTINATION.
     /// - Handles the transaction from the pod's current planet to set final destination user wallet.
     /// - This is just like planet hop except deliver to destination wallet
-    /// Since we MUST pass the destination wallet address in the accounts section,
-    /// and it must match the set `pod.destination` already; there is no need for further destination checks here.
+    /// - The destination account must match `pod.destination`, or open its commitment
+    ///   with the reveal salt `r` when the pod was launched sealed.
     #[derive(AnchorSerialize, AnchorDeserialize)]
     pub struct LandArgs {
-        pub id: u16,
+        pub id: u64,
         pub c: i64,
         pub l: u64,
+        pub r: Option<[u8; 32]>, // Reveal salt for sealed destinations
     }
     pub fn settle(ctx: Context<LandAccount>, args: LandArgs) -> Result<()> {
         let book = &mut ctx.accounts.book;
+        let pod = &mut ctx.accounts.pod;
         let from: &mut Account<Planet> = &mut ctx.accounts.from_planet;
-           
-        let expect = token_from(args.id, args.l, args.c);
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
+
+        let key = pod.key();
+        let expect = token_from(&key, args.id, args.l, args.c);
+        require!(expect == pod.land_ticket(&key), OridionError::LandArgsMismatch);
 
-        // 1) locate by token (small N; linear scan is fine)
-        let Some(i) = book.tickets.iter().position(|t| *t == expect)
-        else { return err!(OridionError::TicketNotFound) };
+        // 0) A pod is delivered once, by either settle or user_settle. Consumes the land ticket.
+        book.land_pod(&key, pod, None, PodState::Landed, now)?;
+        pod.record(ActivityAction::Land, &clock);
+
+        pod.require_at(&from.key())?;
+        from.enter(pod.key(), None, now, ctx.accounts.universe.lock_expiry())?;
+        pod.require_destination(&ctx.accounts.destination.key(), args.r)?;
+        require_not_denied(&ctx.accounts.destination_deny)?;
 
         // If token matches, then we can proceed with confidence that nothing has been tampered with.
 
@@ -942,8 +1470,12 @@ pub mod oridion {
         ctx.accounts.destination.add_lamports(delivery_lamports)?;
         from.sub_lamports(delivery_lamports)?;
 
-        // 4) erase in O(1) without shifting
-        book.tickets.swap_remove(i);
+        mark_pod_entry(
+            pod,
+            ctx.accounts.pod_meta.as_mut(),
+            ctx.accounts.pod_meta_page.as_mut(),
+            PodEntryStatus::Landed,
+        )?;
 
         //Release planet lock
         release_planet_lock(from)?;
@@ -951,58 +1483,182 @@ pub mod oridion {
     }
 
 
-    // Force land pod by signer
-    // Can force land to any destination. Pod auto close.
-    pub fn user_settle(ctx: Context<EmergencyLandByCreator>, _id: u16) -> Result<()> {
+    /// LAND A SINGLE LEG OF A SPLIT POD
+    /// - Consumes the leg's ticket and delivers its lamports from the pod's current planet.
+    /// - The pod drains leg by leg; once empty it stays parked until `reclaim`.
+    pub fn settle_leg(ctx: Context<LandLeg>, index: u8) -> Result<()> {
+        let book = &mut ctx.accounts.book;
         let pod = &mut ctx.accounts.pod;
-        let from_planet = &mut ctx.accounts.from_planet;
-        let delivery_lamports = pod.lamports;
+        let from = &mut ctx.accounts.from_planet;
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp;
 
-        let now = Clock::get()?.unix_timestamp;
+        require!(!pod.is_landed(), OridionError::AlreadyLanded);
         require!(
-            now > pod.created_at + 180, // 3 minutes
-            OridionError::TooSoonToEmLand
+            matches!(pod.state, PodState::Hopping | PodState::ReadyToLand),
+            OridionError::InTransit
         );
-
-        // Check planet is unlocked
-        validate_planet_is_usable(from_planet, pod.key())?;
-
-        // VALIDATION: Prevent a double-landing or underfunded source
-        require!(delivery_lamports > 0, OridionError::AlreadyLanded);
+        pod.require_at(&from.key())?;
+        from.enter(pod.key(), None, now, ctx.accounts.universe.lock_expiry())?;
+
+        let leg = ctx.accounts.pod_legs.legs
+            .get_mut(index as usize)
+            .ok_or(OridionError::InvalidLegs)?;
+        require!(leg.status != 2, OridionError::AlreadyLanded);
+        require!(leg.status == 1 && now >= leg.land_at, OridionError::LegNotReady);
         require!(
-            from_planet.get_lamports() >= delivery_lamports,
-            OridionError::PlanetNotEnoughFundsError
+            ctx.accounts.destination.key().to_bytes() == leg.destination,
+            OridionError::InvalidDestination
         );
+        require_not_denied(&ctx.accounts.destination_deny)?;
+
+        let expect = leg_token_from(&pod.key(), pod.id, index, leg.lamports, pod.created_at);
+        book.take(&expect)?;
+
+        let delivery_lamports = leg.lamports;
+        require!(from.get_lamports() >= delivery_lamports, OridionError::PlanetNotEnoughFundsError);
 
         // TRANSFER funds from Planet → Destination
         ctx.accounts.destination.add_lamports(delivery_lamports)?;
-        from_planet.sub_lamports(delivery_lamports)?;
+        from.sub_lamports(delivery_lamports)?;
+
+        leg.status = 2;
+        pod.lamports = pod.lamports
+            .checked_sub(delivery_lamports)
+            .ok_or(OridionError::UnusualMathError)?;
+        pod.last_process_at = now;
+        pod.record(ActivityAction::LegLand, &clock);
+
+        // Last leg delivered - the pod is fully drained
+        if pod.lamports == 0 {
+            pod.land(PodState::Landed, now)?;
+            mark_pod_entry(
+                pod,
+                ctx.accounts.pod_meta.as_mut(),
+                ctx.accounts.pod_meta_page.as_mut(),
+                PodEntryStatus::Landed,
+            )?;
+        }
 
-        release_planet_lock(from_planet)?;
+        release_planet_lock(from)?;
         Ok(())
     }
 
 
-    //Close pod - Removes pod id from pod meta
+    // Force land pod by signer
+    // Can force land to any destination. Pod auto close.
+    pub fn user_settle(ctx: Context<EmergencyLandByCreator>, _id: u64) -> Result<()> {
+        emergency_land(
+            &mut ctx.accounts.pod,
+            ctx.accounts.pod_legs.as_ref(),
+            &mut ctx.accounts.book,
+            &mut ctx.accounts.from_planet,
+            &ctx.accounts.destination,
+            &ctx.accounts.destination_deny,
+            ctx.accounts.universe.lock_expiry(),
+        )?;
+        // The pod account closes with this instruction
+        mark_pod_entry(
+            &ctx.accounts.pod,
+            Some(&mut ctx.accounts.pod_meta),
+            ctx.accounts.pod_meta_page.as_mut(),
+            PodEntryStatus::Closed,
+        )
+    }
+
+
+    // Force land a pod spawned by a schedule.
+    // The schedule creator or the pod authority signs; spawned pods have no pod meta entry.
+    pub fn user_settle_scheduled(ctx: Context<EmergencyLandScheduled>, _schedule: Pubkey, _index: u16) -> Result<()> {
+        let pod_key = ctx.accounts.pod.key();
+        ctx.accounts.pod.require_scheduled_owner(&pod_key, &ctx.accounts.owner.key())?;
+        emergency_land(
+            &mut ctx.accounts.pod,
+            None,
+            &mut ctx.accounts.book,
+            &mut ctx.accounts.from_planet,
+            &ctx.accounts.destination,
+            &ctx.accounts.destination_deny,
+            ctx.accounts.universe.lock_expiry(),
+        )
+    }
+
+
+    // Force land a private pod.
+    // The owner proves ownership with the commitment secret, or by signing as the pod authority.
+    pub fn user_settle_private(
+        ctx: Context<EmergencyLandPrivate>,
+        commitment: [u8; 32],
+        secret: Option<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.pod.require_owner(&commitment, secret, &ctx.accounts.owner.key())?;
+        emergency_land(
+            &mut ctx.accounts.pod,
+            None,
+            &mut ctx.accounts.book,
+            &mut ctx.accounts.from_planet,
+            &ctx.accounts.destination,
+            &ctx.accounts.destination_deny,
+            ctx.accounts.universe.lock_expiry(),
+        )
+    }
+
+
+    //Close pod - Marks the pod closed in its pod meta. Rent returns to whoever paid it.
     pub fn reclaim(ctx: Context<ClosePod>) -> Result<()> {
         let pod = &ctx.accounts.pod;
-        let landbook = &ctx.accounts.book;
 
         // 1) Cooldown: must be >= 5 minutes after land_at
         const CLOSE_GRACE_SECS: i64 = 300; // 5 minutes
         let now = Clock::get()?.unix_timestamp;
         require!(now >= (pod.land_at + CLOSE_GRACE_SECS),OridionError::PodCloseError);
 
-        // 2) Ensure the LandBook ticket is already CONSUMED (i.e., NOT present)
-        let ticket = token_from(pod.id, pod.lamports, pod.created_at);
-        let still_present = landbook.tickets.iter().any(|t| *t == ticket);
-        require!(!still_present, OridionError::TicketFoundCloseError);
+        // 2) Ensure every ticket of the pod is already CONSUMED
+        require_closable(pod, &ctx.accounts.book, ctx.accounts.pod_legs.as_ref())?;
+
+        // 3) Mark the pod closed in its creator's index
+        // If all checks pass, returning Ok(()) triggers `close = rent_to` on `pod`.
+        mark_pod_entry(
+            pod,
+            ctx.accounts.pod_meta.as_mut(),
+            ctx.accounts.pod_meta_page.as_mut(),
+            PodEntryStatus::Closed,
+        )
+    }
+
+
+    // Creator closes their own finished pod and takes the rent back.
+    pub fn close_pod(ctx: Context<CreatorClosePod>, _id: u64) -> Result<()> {
+        let pod = &ctx.accounts.pod;
+        require_closable(pod, &ctx.accounts.book, ctx.accounts.pod_legs.as_ref())?;
+        mark_pod_entry(
+            pod,
+            Some(&mut ctx.accounts.pod_meta),
+            ctx.accounts.pod_meta_page.as_mut(),
+            PodEntryStatus::Closed,
+        )
+    }
+
 
-        // 3) Remove pod id from pod_meta
-        let meta = &mut ctx.accounts.pod_meta;
-        meta.remove_id(pod.id);
-        
-        // If all checks pass, returning Ok(()) triggers `close = manager` on `pod`.
+    // Creator closes the last overflow page of their pod meta once its pods are closed.
+    pub fn close_meta_page(ctx: Context<CloseMetaPage>) -> Result<()> {
+        let pod_meta = &mut ctx.accounts.pod_meta;
+        let page = &ctx.accounts.pod_meta_page;
+        require!(page.page + 1 == pod_meta.pages, OridionError::PodMetaNotEmpty);
+        require!(page.index.is_empty(), OridionError::PodMetaNotEmpty);
+        pod_meta.pages -= 1;
+        Ok(())
+    }
+
+
+    // Creator closes an empty pod meta and takes the rent back.
+    // Every pod must be reclaimed and its volume must have left the limit window.
+    pub fn close_pod_meta(ctx: Context<ClosePodMeta>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.pod_meta.is_empty(now, &ctx.accounts.universe.limits),
+            OridionError::PodMetaNotEmpty
+        );
         Ok(())
     }
 
@@ -1031,4 +1687,60 @@ pub mod oridion {
         Ok(())
     }
 
+
+    ///-------------------------------------------------------------------///
+    /// MIGRATIONS - Manager only
+    /// Rewrite accounts created before the upgrade into the current layouts.
+    /// Each account is migrated once; an account already in its current layout is rejected.
+    ///-------------------------------------------------------------------///
+    pub fn migrate_universe(ctx: Context<Migrate>) -> Result<()> {
+        let info = ctx.accounts.account.to_account_info();
+        let universe = read_legacy::<LegacyUniverse, Universe>(&info)?.upgrade();
+        write_migrated(&info, &ctx.accounts.manager, &ctx.accounts.system_program, &universe)
+    }
+
+    pub fn migrate_treasury(ctx: Context<Migrate>) -> Result<()> {
+        let info = ctx.accounts.account.to_account_info();
+        let treasury = read_legacy::<LegacyTreasury, Treasury>(&info)?.upgrade();
+        write_migrated(&info, &ctx.accounts.manager, &ctx.accounts.system_program, &treasury)
+    }
+
+    pub fn migrate_planet(ctx: Context<Migrate>) -> Result<()> {
+        let info = ctx.accounts.account.to_account_info();
+        let planet = read_legacy::<LegacyPlanet, Planet>(&info)?.upgrade();
+        write_migrated(&info, &ctx.accounts.manager, &ctx.accounts.system_program, &planet)
+    }
+
+    /// Legacy stars were seeded by name like registered ones; the name proves the address.
+    pub fn migrate_star(ctx: Context<Migrate>, name: String) -> Result<()> {
+        let info = ctx.accounts.account.to_account_info();
+        let (address, bump) = Pubkey::find_program_address(
+            &[STAR_SEED_PRE, name.as_ref(), STAR_SEED_POST],
+            ctx.program_id,
+        );
+        require_keys_eq!(address, info.key(), OridionError::NotLegacyAccount);
+        let star = read_legacy::<LegacyStar, Star>(&info)?.upgrade(bump)?;
+        write_migrated(&info, &ctx.accounts.manager, &ctx.accounts.system_program, &star)
+    }
+
+    pub fn migrate_pod_meta(ctx: Context<Migrate>) -> Result<()> {
+        let info = ctx.accounts.account.to_account_info();
+        let pod_meta = read_legacy::<LegacyPodMeta, PodMeta>(&info)?.upgrade();
+        write_migrated(&info, &ctx.accounts.manager, &ctx.accounts.system_program, &pod_meta)
+    }
+
+    /// A waiting land ticket is reissued in the current format, bound to the pod address.
+    pub fn migrate_pod(ctx: Context<MigratePod>) -> Result<()> {
+        let info = ctx.accounts.pod.to_account_info();
+        let legacy = read_legacy::<LegacyPod, Pod>(&info)?;
+        let book = &mut ctx.accounts.book;
+        let ticketed = book.tickets.contains(&legacy.land_ticket());
+        let pod = legacy.upgrade(&info.key(), ticketed)?;
+        if ticketed {
+            book.discard(&legacy.land_ticket());
+            book.tickets.push(pod.land_ticket(&info.key()));
+        }
+        write_migrated(&info, &ctx.accounts.manager, &ctx.accounts.system_program, &pod)
+    }
+
 }
\ No newline at end of file
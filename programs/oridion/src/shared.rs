@@ -1,5 +1,8 @@
 use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
 use crate::account_land::LandBook;
+use crate::oridion::PodArgs;
 use super::*;
 
 #[repr(u8)]
@@ -19,45 +22,97 @@ pub fn mode_string(mode: u8) -> &'static str {
     }
 }
 
+/// Validates launch arguments and returns the landing timestamp.
+/// Called through `Universe::plan_launch`, so `quote` and every launch path reject the same inputs.
+pub fn validate_pod_args(args: &PodArgs, universe: &Universe, now: i64) -> Result<i64> {
+    //Prevent 0 amount or dust attacks (500 lamports)
+    require!(args.l > 500, OridionError::InvalidDepositAmount);
+    require!(
+        universe.limits.max_pod == 0 || args.l <= universe.limits.max_pod,
+        OridionError::PodTooLarge
+    );
+
+    //1 Delay, 2 Instant, 3 Manual
+    require!((1..=3).contains(&args.m), OridionError::InvalidMode);
+
+    // Basic validity checks
+    require!(nonzero_32(&args.p), OridionError::InvalidPasscode);
+
+    //Set the landing timestamp from delay.
+    //We no longer pass the landing timestamp because of timezone/vpn client side issues.
+    let land_at = now + args.d as i64;
+
+    // If delay mode, require that the landing time is after now.
+    if args.m == 1 {
+        require!(land_at > now,OridionError::InvalidLandingTimestamp);
+    }
 
-// Map a hash to a jitter in [min_s, max_s] (inclusive)
-fn jitter_seconds(pod: &Pod, slot: u64, min_s: i64, max_s: i64) -> i64 {
-    let idb = pod.id.to_le_bytes();
-    let hopsb = pod.hops.to_le_bytes();
-    let cab = pod.created_at.to_le_bytes();
-    let slotb = slot.to_le_bytes();
+    // Delay must be within the configured bounds for the mode.
+    universe.delay_bounds(args.m)?.check(args.d)?;
+    Ok(land_at)
+}
 
-    let h = hashv(&[
-        b"ORIDION_HOP_JITTER_V1",
-        &idb,
-        &hopsb,
-        &cab,
-        &slotb,
-    ]).to_bytes();
 
-    let r = u64::from_le_bytes(h[0..8].try_into().unwrap());
-    let span = (max_s - min_s + 1) as u64; // e.g., 121
-    min_s + (r % span) as i64
+/// Planet to planet hop. The pod must be able to start a hop.
+pub fn hop_pod(
+    pod: &mut Account<Pod>,
+    book: &mut Account<LandBook>,
+    legs: Option<&mut Account<PodLegs>>,
+) -> Result<()> {
+    require!(pod.state.can_hop(), OridionError::InvalidPodTransition);
+    advance_pod(pod, book, legs)
+}
+
+/// Arrival of a star or scatter hop. The pod must be in transit.
+pub fn end_transit_hop(
+    pod: &mut Account<Pod>,
+    book: &mut Account<LandBook>,
+    legs: Option<&mut Account<PodLegs>>,
+) -> Result<()> {
+    require!(pod.state.is_in_transit(), OridionError::NotInTransit);
+    advance_pod(pod, book, legs)
 }
 
 /// Handles common hop details
-pub fn hop_pod(pod: &mut Account<Pod>, book: &mut Account<LandBook>) -> Result<()> {
+/// Split pods must pass their legs so due legs receive their land tickets.
+fn advance_pod(
+    pod: &mut Account<Pod>,
+    book: &mut Account<LandBook>,
+    legs: Option<&mut Account<PodLegs>>,
+) -> Result<()> {
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
     let land_time = pod.land_at;
 
     pod.hops = pod.hops.saturating_add(1);
-    pod.last_process = 1; // hop
     pod.last_process_at = now;
+    pod.record(ActivityAction::Hop, &clock);
+
+    // === SPLIT POD === //
+    // Each leg gets its own ticket once it is close to landing.
+    // The pod itself never gets a ticket; it drains leg by leg in `settle_leg`.
+    if pod.legs > 0 {
+        let legs = legs.ok_or(OridionError::PodLegsMissing)?;
+        legs.arm_due(&pod.key(), pod, book, now, 240)?;
+        if legs.legs.iter().all(|leg| leg.status != 0) {
+            pod.transition(PodState::ReadyToLand)?;
+            pod.next_process_at = land_time;
+        } else {
+            let jitter = pod.hop_jitter(clock.slot);
+            pod.transition(PodState::Hopping)?;
+            pod.next_process_at = now.saturating_add(jitter);
+        }
+        return Ok(());
+    }
 
     // 1 = Delay, 2 = Instant, 3 = Manual
 
     // === INSTANT MODE (mode == 2) === //
     if pod.mode == 2 {
         // Move straight to land after this hop
-        let tok = token_from(pod.id, pod.lamports, pod.created_at);
+        let tok = pod.land_ticket(&pod.key());
 
-        if !book.tickets.iter().any(|t| *t == tok) {
+        if !book.tickets.contains(&tok) {
             require!(book.tickets.len() < 128, OridionError::LandBookFull);
             book.tickets.push(tok);
         }
@@ -65,7 +120,7 @@ pub fn hop_pod(pod: &mut Account<Pod>, book: &mut Account<LandBook>) -> Result<(
         // Write into the existing [u8;32] field, zero-padded
         write_ticket_into_passcode(&mut pod.passcode_hash, &tok);
 
-        pod.next_process = 1;         // land
+        pod.transition(PodState::ReadyToLand)?;
         pod.next_process_at = now;    // or now + 1..2s if you prefer a tiny delay
         return Ok(());
     }
@@ -75,27 +130,25 @@ pub fn hop_pod(pod: &mut Account<Pod>, book: &mut Account<LandBook>) -> Result<(
         let remaining = land_time.saturating_sub(now);
 
         if remaining <= 240 {
-            // Only transition to LAND once
-            if pod.next_process != 1 {
-                pod.next_process = 1;  // land
-                pod.next_process_at = land_time;
-
-                // Compute land token (binds id+dest+amount+created_at)
-                let tok = token_from(pod.id, pod.lamports, pod.created_at);
-
-                // Push only if not already present (idempotent)
-                if !book.tickets.iter().any(|t| *t == tok) {
-                    // Optional: cap to prevent accidental growth
-                    require!(book.tickets.len() < 128, OridionError::LandBookFull);
-                    book.tickets.push(tok);
-                }
-
-                // Write into the existing [u8;32] field, zero-padded
-                write_ticket_into_passcode(&mut pod.passcode_hash, &tok);
+            // Only transition to LAND once (ReadyToLand pods cannot hop again)
+            pod.transition(PodState::ReadyToLand)?;
+            pod.next_process_at = land_time;
+
+            // Compute land token (binds id+dest+amount+created_at)
+            let tok = pod.land_ticket(&pod.key());
+
+            // Push only if not already present (idempotent)
+            if !book.tickets.contains(&tok) {
+                // Optional: cap to prevent accidental growth
+                require!(book.tickets.len() < 128, OridionError::LandBookFull);
+                book.tickets.push(tok);
             }
+
+            // Write into the existing [u8;32] field, zero-padded
+            write_ticket_into_passcode(&mut pod.passcode_hash, &tok);
         } else {
-            // Randomize the next hop between 2–4 minutes
-            let jitter = jitter_seconds(pod, clock.slot, 120, 240);
+            // Randomize the next hop between 2–4 minutes (stretched for long delays)
+            let jitter = pod.hop_jitter(clock.slot);
             let mut next = now.saturating_add(jitter);
 
             // Clamp so we don't schedule past the planned land time
@@ -103,76 +156,155 @@ pub fn hop_pod(pod: &mut Account<Pod>, book: &mut Account<LandBook>) -> Result<(
                 // a few seconds before land to ensure we cross the threshold next time
                 next = land_time.saturating_sub(5);
             }
-            pod.next_process = 0;  // hop
+            pod.transition(PodState::Hopping)?;
             pod.next_process_at = next;
         }
+        return Ok(());
     }
-    Ok(())
+
+    // === MANUAL MODE (mode == 3) === //
+    pod.transition(PodState::Hopping)
 }
 
 
-/// Generate random percent (integer) between 10 - 90
-pub fn get_random_percent() -> u8 {
-    if let Ok(clock) = Clock::get() {
-        (clock.unix_timestamp % 81 + 10) as u8
-    } else {
-        50
-    }
+/// Moves a launch deposit from the creator through the universe into the first
+/// planet, and the fee into the treasury.
+pub fn fund_launch<'info>(
+    creator: &Signer<'info>,
+    universe: &Account<'info, Universe>,
+    treasury: &mut Account<'info, Treasury>,
+    planet: &mut Account<'info, Planet>,
+    system_program: &Program<'info, System>,
+    lamports: u64,
+    fee: u64,
+) -> Result<()> {
+    // Validate the creator's funds to cover the total cost
+    let total_payment = lamports
+        .checked_add(fee)
+        .ok_or(OridionError::UnusualMathError)?;
+    require!(
+        creator.lamports() >= total_payment,
+        OridionError::InsufficientFunds
+    );
+
+    invoke(
+        &transfer(creator.key, universe.to_account_info().key, lamports),
+        &[
+            creator.to_account_info(),
+            universe.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+    invoke(
+        &transfer(creator.key, &treasury.key(), fee),
+        &[
+            creator.to_account_info(),
+            treasury.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    treasury.accrue(fee)?;
+
+    // Planet bookkeeping - Increment visits
+    planet.visits += 1;
+
+    // TRANSACTION - From galaxy to planet
+    planet.add_lamports(lamports)?;
+    universe.sub_lamports(lamports)?;
+    Ok(())
 }
 
 
-/// Checks if a planet is unlocked or the lock has timed out
-/// Used for Planet hops and Start hops
-/// End hops check to validate is locked below.
-pub fn validate_planet_is_usable (
-    from: &Planet,
-    pod_key: Pubkey
+/// Emergency delivery shared by `user_settle` and `user_settle_private`.
+/// Can force land to any destination. The pod account closes afterwards.
+pub fn emergency_land<'info>(
+    pod: &mut Account<'info, Pod>,
+    legs: Option<&Account<'info, PodLegs>>,
+    book: &mut Account<'info, LandBook>,
+    from_planet: &mut Account<'info, Planet>,
+    destination: &SystemAccount<'info>,
+    destination_deny: &UncheckedAccount<'info>,
+    lock_expiry: i64,
 ) -> Result<()> {
-    // The withdrawing planet must be unlocked to proceed.
-    let is_unlocked = from.locked_at == 0;
-    let locked_by_pod = from.locked_by == pod_key;
+    let delivery_lamports = pod.lamports;
+    require_not_denied(destination_deny)?;
 
-    if is_unlocked || locked_by_pod {
-        return Ok(());
-    }
-    
-    //If locked by another planet, then the lock must be expired to use it. 
-    if from.locked_at > 0 {
-        let clock = Clock::get()?;
-        let now = clock.unix_timestamp;
-        let lock_age = now - from.locked_at;
-        require!(lock_age >= LOCK_EXPIRE_SECONDS, OridionError::PlanetStillLocked);
-    }
-    Ok(())
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    require!(
+        now > pod.created_at + 180, // 3 minutes
+        OridionError::TooSoonToEmLand
+    );
+
+    // Check planet holds the pod and lock it
+    pod.require_at(&from_planet.key())?;
+    from_planet.enter(pod.key(), None, now, lock_expiry)?;
+
+    // VALIDATION: Prevent a double-landing or underfunded source
+    // Removes any land tickets so `settle` can never deliver this pod again.
+    // Split pods close their legs account along with the pod.
+    let key = pod.key();
+    book.land_pod(&key, pod, legs.map(|l| &**l), PodState::Emergency, now)?;
+    pod.record(ActivityAction::Emergency, &clock);
+    require!(delivery_lamports > 0, OridionError::AlreadyLanded);
+    require!(
+        from_planet.get_lamports() >= delivery_lamports,
+        OridionError::PlanetNotEnoughFundsError
+    );
+
+    // TRANSFER funds from Planet → Destination
+    destination.add_lamports(delivery_lamports)?;
+    from_planet.sub_lamports(delivery_lamports)?;
+
+    release_planet_lock(from_planet)
 }
 
 
+/// Moves the referrer's cut of `fee` from the treasury into its partner PDA.
+/// The cut is taken before the fee split, so it leaves `undistributed` too.
+pub fn pay_referrer(treasury: &mut Account<Treasury>, referrer: &mut Account<Partner>, fee: u64) -> Result<()> {
+    let share = referrer.share_of(fee);
+    if share == 0 {
+        return Ok(());
+    }
+    treasury.undistributed = treasury.undistributed.saturating_sub(share);
+    treasury.sub_lamports(share)?;
+    referrer.add_lamports(share)?;
+    referrer.accrue(share)
+}
 
-/// Validates that the withdrawing planet account is locked before any usage.
-pub fn validate_planet_locked_by_pod(
-    from: &Planet,
-    pod_key: Pubkey,
-) -> Result<()> {
 
-    // The withdrawing planet must be locked to proceed.
-    let is_locked = from.locked_at != 0;
-    require!(is_locked, OridionError::PlanetNotLocked);
+/// A finished pod can only be closed once none of its tickets can be delivered.
+pub fn require_closable(pod: &Account<Pod>, book: &LandBook, legs: Option<&Account<PodLegs>>) -> Result<()> {
+    let ticket = pod.land_ticket(&pod.key());
+    require!(!book.tickets.contains(&ticket), OridionError::TicketFoundCloseError);
 
-    // Must be locked by this pod's pda key
-    let owns_lock = from.locked_by == pod_key;
-    require!(owns_lock, OridionError::NotAuthorizedToHop);
+    // Split pods must have delivered every leg
+    if pod.legs > 0 {
+        let legs = legs.ok_or(OridionError::PodLegsMissing)?;
+        require!(legs.all_landed(), OridionError::TicketFoundCloseError);
+    }
+    Ok(())
+}
 
-    // Check for lock expiration (in case it sat too long)
-    // If expired (more than 30 seconds) must send a new lock request before hopping.
-    let clock = Clock::get()?;
-    let now = clock.unix_timestamp;
-    let lock_age = now - from.locked_at;
-    require!(lock_age <= LOCK_EXPIRE_SECONDS, OridionError::LockExpired);
 
+/// Fails if `entry` (a denylist PDA checked via seeds by the caller) holds an entry.
+pub fn require_not_denied(entry: &UncheckedAccount) -> Result<()> {
+    require!(entry.data_is_empty(), OridionError::AddressDenied);
     Ok(())
 }
 
 
+/// Generate random percent (integer) between 10 - 90
+pub fn get_random_percent() -> u8 {
+    if let Ok(clock) = Clock::get() {
+        (clock.unix_timestamp % 81 + 10) as u8
+    } else {
+        50
+    }
+}
+
 /// Resets lock and releases it to be used for withdrawal
 pub fn release_planet_lock(planet: &mut Planet) -> Result<()> {
     planet.locked_at = 0;
@@ -187,7 +319,34 @@ pub fn nonzero_32(x: &[u8; 32]) -> bool {
 
 /// Generates land token for the guarantee of unchanged destination.
 pub fn token_from(
-    id: u16,
+    pod: &Pubkey,
+    id: u64,
+    amount: u64,
+    created_at: i64,
+) -> [u8;16] {
+    let idb = id.to_le_bytes();
+    let amb = amount.to_le_bytes();
+    let cab = created_at.to_le_bytes();
+
+    // include a domain/version string to future-proof the format.
+    // V2 binds the pod address so ids can repeat across creators.
+    let digest = hashv(&[
+        b"ORIDION_LAND_V2",
+        pod.as_ref(),
+        &idb,
+        &amb,
+        &cab,
+    ]).to_bytes();
+
+    digest[0..16].try_into().unwrap()
+}
+
+
+/// Generates the land token for a single leg of a split pod.
+pub fn leg_token_from(
+    pod: &Pubkey,
+    id: u64,
+    index: u8,
     amount: u64,
     created_at: i64,
 ) -> [u8;16] {
@@ -195,10 +354,11 @@ pub fn token_from(
     let amb = amount.to_le_bytes();
     let cab = created_at.to_le_bytes();
 
-    // include a domain/version string to future-proof the format
     let digest = hashv(&[
-        b"ORIDION_LAND_V1",
+        b"ORIDION_LAND_LEG_V2",
+        pod.as_ref(),
         &idb,
+        &[index],
         &amb,
         &cab,
     ]).to_bytes();
@@ -11,6 +11,24 @@ pub const PLANET_PDA_SEED_POST: &[u8] = b"_NET_";
 pub const STAR_SEED_PRE: &[u8] = b"_INFINITY_";
 pub const STAR_SEED_POST: &[u8] = b"_BEYOND_";
 pub const MAX_PLANET_TITLE_LENGTH: usize = 10; // 4 + 6 (MAX 6 CHAR)
-pub const MAX_DELAY_ALLOWED: u32 = 86400; //24 hours
+pub const MAX_STAR_NAME_LENGTH: usize = 10; // Star names seed the star PDA
+pub const MAX_DELAY_ALLOWED: u32 = 86400; //24 hours (default max delay per mode)
+pub const MAX_DELAY_CEILING: u32 = 2_592_000; //30 days (hard limit for configured delays)
+pub const MIN_DELAY_MODE_DELAY: u32 = 180; // Default min delay for Delay mode
+pub const HOP_SCALE_PERIOD: u32 = 86400; // Delays past each period stretch hop spacing by one step
+pub const HOP_INTERVAL_SECONDS: u32 = 180; // Average seconds between hops (fee estimate)
+pub const BPS_DENOMINATOR: u64 = 10_000;
 
-pub const LOCK_EXPIRE_SECONDS: i64 = 30; // How many seconds locks expire
\ No newline at end of file
+pub const MIN_SCHEDULE_INTERVAL: u32 = 3600; // 1 hour between scheduled pods
+pub const MAX_SCHEDULE_PERIODS: u16 = 104; // 2 years of weekly pods
+pub const POD_ACTIVITY_LOG_SIZE: usize = 20; // Pod activity ring buffer entries
+pub const MAX_POD_LEGS: usize = 5; // Max delivery legs for a split pod
+pub const MAX_BATCH_PODS: usize = 8; // Max pods moved by one batch hop
+pub const CREATOR_VOLUME_BUCKETS: usize = 8; // Buckets per creator volume window
+pub const MAX_POD_NOTE_LEN: usize = 128; // Max ciphertext bytes in a pod note
+pub const DISCLOSURE_LEN: usize = 32 + 80 + 16; // Ephemeral key + DisclosureMeta + tag
+
+pub const MAX_PARTNER_BPS: u16 = 5_000; // Partners earn at most half of a launch fee
+pub const MAX_FREEZE_SECONDS: u32 = 2_592_000; // 30 days compliance hold
+pub const LOCK_EXPIRE_SECONDS: i64 = 30; // Default seconds until a planet lock expires
+pub const MAX_LOCK_EXPIRE_SECONDS: u32 = 600; // Ceiling for the configured lock expiry
\ No newline at end of file
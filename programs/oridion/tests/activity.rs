@@ -0,0 +1,39 @@
+//! Pod activity ring buffer.
+
+use anchor_lang::prelude::Clock;
+use oridion::account_pod::{ActivityAction, Pod};
+
+fn clock(i: i64) -> Clock {
+    Clock { unix_timestamp: 1_700_000_000 + i, slot: 1_000 + i as u64, ..Clock::default() }
+}
+
+#[test]
+fn records_in_order_until_full() {
+    let mut pod = Pod::default();
+    pod.record(ActivityAction::Launch, &clock(0));
+    pod.record(ActivityAction::Star2, &clock(1));
+    pod.record(ActivityAction::Hop, &clock(2));
+
+    let log = pod.activity_log();
+    let actions: Vec<_> = log.iter().map(|a| a.action).collect();
+    assert_eq!(actions, [ActivityAction::Launch, ActivityAction::Star2, ActivityAction::Hop]);
+    assert_eq!(log[1].timestamp, 1_700_000_001);
+    assert_eq!(log[1].slot, 1_001);
+}
+
+#[test]
+fn overwrites_oldest_entries_when_full() {
+    let mut pod = Pod::default();
+    pod.record(ActivityAction::Launch, &clock(0));
+    for i in 1..25 {
+        pod.record(ActivityAction::Hop, &clock(i));
+    }
+    pod.record(ActivityAction::Land, &clock(25));
+
+    let log = pod.activity_log();
+    assert_eq!(log.len(), 20);
+    assert_eq!(pod.activity_count, 26);
+    assert_eq!(log.first().unwrap().slot, 1_006, "oldest six entries were overwritten");
+    assert_eq!(log.last().unwrap().action, ActivityAction::Land);
+    assert!(log.windows(2).all(|w| w[0].slot < w[1].slot));
+}
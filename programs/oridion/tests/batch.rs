@@ -0,0 +1,29 @@
+//! Batch hops net pod movements into one flow per planet.
+
+use oridion::all_accounts::{net_flows, BatchMove};
+use oridion::errors::OridionError;
+
+fn hop(from: usize, to: usize, lamports: u64) -> BatchMove {
+    BatchMove { from, to, lamports }
+}
+
+#[test]
+fn crossing_pods_net_out() {
+    let flows = net_flows(3, &[hop(0, 1, 500), hop(1, 0, 300), hop(1, 2, 50)]).unwrap();
+    assert_eq!(flows, vec![-200, 150, 50]);
+    assert_eq!(flows.iter().sum::<i128>(), 0);
+}
+
+#[test]
+fn swapping_equal_pods_moves_nothing() {
+    let flows = net_flows(2, &[hop(0, 1, 700), hop(1, 0, 700)]).unwrap();
+    assert_eq!(flows, vec![0, 0]);
+}
+
+#[test]
+fn moves_must_stay_on_batch_planets() {
+    let err = net_flows(2, &[hop(0, 2, 1)]).unwrap_err();
+    assert_eq!(err, OridionError::InvalidBatch.into());
+    let err = net_flows(2, &[hop(1, 1, 1)]).unwrap_err();
+    assert_eq!(err, OridionError::HopErrorToAndFromAreSame.into());
+}
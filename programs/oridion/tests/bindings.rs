@@ -0,0 +1,116 @@
+//! Negative tests for the planet, star and meta bindings enforced by the hop
+//! and land instructions.
+
+use anchor_lang::prelude::Pubkey;
+use oridion::account_pod::Pod;
+use oridion::account_star::{require_unique_stars, Star};
+use oridion::all_accounts::TransitMeta;
+use oridion::errors::OridionError;
+
+fn keys<const N: usize>() -> [Pubkey; N] {
+    std::array::from_fn(|_| Pubkey::new_unique())
+}
+
+#[test]
+fn pod_location_must_match_planet() {
+    let [here, elsewhere] = keys();
+    let pod = Pod { location: here, ..Pod::default() };
+
+    pod.require_at(&here).unwrap();
+    let err = pod.require_at(&elsewhere).unwrap_err();
+    assert_eq!(err, OridionError::PlanetNotPodLocation.into());
+}
+
+fn free_star() -> Star {
+    Star { amount: 0, held_by: Pubkey::default(), held_at: 0, bump: 0 }
+}
+
+#[test]
+fn star_end_requires_the_holding_pod() {
+    let [pod, other] = keys();
+    let mut star = free_star();
+    star.hold(pod, 40, 1).unwrap();
+
+    let err = star.release(&other).unwrap_err();
+    assert_eq!(err, OridionError::StarNotHeld.into());
+    assert_eq!(star.release(&pod).unwrap(), 40);
+
+    // Released stars return to the pool and cannot be ended twice.
+    assert_eq!(star.held_by, Pubkey::default());
+    let err = star.release(&pod).unwrap_err();
+    assert_eq!(err, OridionError::StarNotHeld.into());
+}
+
+#[test]
+fn held_stars_cannot_be_reused() {
+    let [pod, other] = keys();
+    let mut star = free_star();
+    star.hold(pod, 40, 1).unwrap();
+
+    let err = star.hold(other, 10, 2).unwrap_err();
+    assert_eq!(err, OridionError::StarInUse.into());
+    let err = free_star().hold(pod, 0, 1).unwrap_err();
+    assert_eq!(err, OridionError::StarHopCalculationError.into());
+}
+
+#[test]
+fn hop_stars_must_be_distinct() {
+    let [one, two, three] = keys();
+    require_unique_stars(&[one, two, three]).unwrap();
+    for stars in [[one, one, three], [one, two, two], [three, two, three]] {
+        let err = require_unique_stars(&stars).unwrap_err();
+        assert_eq!(err, OridionError::HopErrorStarsMustBeUnique.into());
+    }
+}
+
+#[test]
+fn scatter_end_requires_recorded_sources() {
+    let [from, p1, p2, p3, other] = keys();
+    let meta = TransitMeta {
+        from,
+        to_pdas: [p1, p2, p3],
+        amounts: [1, 2, 3],
+        created_at: 1,
+        bump: 0,
+    };
+
+    meta.require_sources(&from, &[p1, p2, p3]).unwrap();
+
+    let err = meta.require_sources(&from, &[p1, p2, other]).unwrap_err();
+    assert_eq!(err, OridionError::ScatterMetaMismatch.into());
+
+    // Order matters: amounts are recorded per planet.
+    let err = meta.require_sources(&from, &[p2, p1, p3]).unwrap_err();
+    assert_eq!(err, OridionError::ScatterMetaMismatch.into());
+
+    // Pod must still be sitting on the planet the scatter started from.
+    let err = meta.require_sources(&other, &[p1, p2, p3]).unwrap_err();
+    assert_eq!(err, OridionError::ScatterMetaMismatch.into());
+}
+
+#[test]
+fn settle_destination_must_match_pod() {
+    let [dest, other] = keys();
+    let pod = Pod { destination: dest.to_bytes(), ..Pod::default() };
+
+    pod.require_destination(&dest, None).unwrap();
+    let err = pod.require_destination(&other, None).unwrap_err();
+    assert_eq!(err, OridionError::InvalidDestination.into());
+}
+
+#[test]
+fn sealed_destination_requires_reveal() {
+    let [dest, other] = keys();
+    let salt = [3u8; 32];
+    let pod = Pod {
+        sealed: true,
+        destination: Pod::destination_commitment(&dest, &salt),
+        ..Pod::default()
+    };
+
+    pod.require_destination(&dest, Some(salt)).unwrap();
+    for (key, reveal) in [(dest, None), (dest, Some([4u8; 32])), (other, Some(salt))] {
+        let err = pod.require_destination(&key, reveal).unwrap_err();
+        assert_eq!(err, OridionError::InvalidDestination.into());
+    }
+}
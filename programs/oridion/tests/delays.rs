@@ -0,0 +1,67 @@
+//! Delay bounds per mode and how hop spacing stretches with the delay.
+
+use oridion::account_pod::{estimate_hops, hop_scale, Pod};
+use oridion::account_universe::{DelayBounds, Universe};
+use oridion::errors::OridionError;
+
+const DAY: u32 = 86_400;
+
+#[test]
+fn delay_mode_rejects_short_delays() {
+    let delays = Universe::default_delays();
+    let bounds = delays[0];
+    bounds.validate(1).unwrap();
+    assert_eq!(bounds.check(0).unwrap_err(), OridionError::DelayTooShort.into());
+    assert_eq!(bounds.check(179).unwrap_err(), OridionError::DelayTooShort.into());
+    bounds.check(180).unwrap();
+    bounds.check(DAY).unwrap();
+    assert_eq!(bounds.check(DAY + 1).unwrap_err(), OridionError::DelayTooLong.into());
+
+    // Instant and Manual may land right away.
+    delays[1].check(0).unwrap();
+    delays[2].check(0).unwrap();
+}
+
+#[test]
+fn bounds_are_validated_before_they_are_stored() {
+    let zero_min = DelayBounds { min: 0, max: DAY };
+    assert_eq!(zero_min.validate(1).unwrap_err(), OridionError::InvalidDelay.into());
+    zero_min.validate(2).unwrap();
+
+    let inverted = DelayBounds { min: 600, max: 300 };
+    assert_eq!(inverted.validate(2).unwrap_err(), OridionError::InvalidDelay.into());
+
+    // 30 days is the ceiling for any configured delay.
+    DelayBounds { min: 180, max: 30 * DAY }.validate(1).unwrap();
+    let past_ceiling = DelayBounds { min: 180, max: 30 * DAY + 1 };
+    assert_eq!(past_ceiling.validate(1).unwrap_err(), OridionError::InvalidDelay.into());
+}
+
+#[test]
+fn hop_spacing_stretches_one_step_per_day() {
+    assert_eq!(hop_scale(0), 1);
+    assert_eq!(hop_scale(180), 1);
+    assert_eq!(hop_scale(DAY), 1);
+    assert_eq!(hop_scale(DAY + 1), 2);
+    assert_eq!(hop_scale(7 * DAY), 7);
+    assert_eq!(hop_scale(30 * DAY), 30);
+
+    // A 7 day pod takes as many hops as a 24 hour pod.
+    assert_eq!(estimate_hops(1, DAY), 480);
+    assert_eq!(estimate_hops(1, 7 * DAY), 480);
+    assert_eq!(estimate_hops(1, 180), 1);
+    assert_eq!(estimate_hops(1, 179), 0);
+    assert_eq!(estimate_hops(2, 7 * DAY), 1);
+    assert_eq!(estimate_hops(3, 7 * DAY), 0);
+}
+
+#[test]
+fn hop_jitter_stays_within_the_scaled_window() {
+    let short = Pod { delay: 3_600, created_at: 1_700_000_000, ..Pod::default() };
+    let week = Pod { delay: 7 * DAY, ..short.clone() };
+    for slot in 0..500 {
+        assert!((120..=240).contains(&short.hop_jitter(slot)));
+        assert!((7 * 120..=7 * 240).contains(&week.hop_jitter(slot)));
+    }
+    assert_eq!(week.hop_jitter(42), week.hop_jitter(42));
+}
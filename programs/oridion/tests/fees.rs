@@ -0,0 +1,97 @@
+//! Fee model: fee = clamp(base + lamports * bps / 10_000 + hops * per_hop, min, max).
+
+use oridion::account_universe::{FeeModel, Universe};
+use oridion::errors::OridionError;
+use oridion::oridion::PodArgs;
+
+#[test]
+fn basis_points_round_down() {
+    let model = FeeModel { bps: 30, ..FeeModel::default() };
+    assert_eq!(model.fee_for(10_000, 0).unwrap(), 30);
+    assert_eq!(model.fee_for(10_333, 0).unwrap(), 30);
+    assert_eq!(model.fee_for(333, 0).unwrap(), 0);
+
+    let model = FeeModel { base: 1_000, bps: 30, per_hop: 50, ..FeeModel::default() };
+    assert_eq!(model.fee_for(1_000_000, 4).unwrap(), 1_000 + 3_000 + 200);
+}
+
+#[test]
+fn min_is_a_floor_and_max_a_cap() {
+    let floor = FeeModel { bps: 10, min: 500, ..FeeModel::default() };
+    assert_eq!(floor.fee_for(1_000, 0).unwrap(), 500);
+    assert_eq!(floor.fee_for(10_000_000, 0).unwrap(), 10_000);
+
+    let capped = FeeModel { bps: 100, max: 2_000, ..FeeModel::default() };
+    assert_eq!(capped.fee_for(10_000_000, 0).unwrap(), 2_000);
+    assert_eq!(capped.fee_for(100_000, 0).unwrap(), 1_000);
+
+    // A zero max leaves the fee uncapped.
+    let uncapped = FeeModel { bps: 100, ..FeeModel::default() };
+    assert_eq!(uncapped.fee_for(10_000_000, 0).unwrap(), 100_000);
+}
+
+#[test]
+fn invalid_models_are_rejected() {
+    FeeModel { bps: 10_000, ..FeeModel::default() }.validate().unwrap();
+    let err = FeeModel { bps: 10_001, ..FeeModel::default() }.validate().unwrap_err();
+    assert_eq!(err, OridionError::InvalidFeeModel.into());
+
+    let err = FeeModel { min: 10, max: 9, ..FeeModel::default() }.validate().unwrap_err();
+    assert_eq!(err, OridionError::InvalidFeeModel.into());
+    FeeModel { min: 10, max: 0, ..FeeModel::default() }.validate().unwrap();
+}
+
+#[test]
+fn fees_past_u64_fail_instead_of_wrapping() {
+    let model = FeeModel { base: u64::MAX, per_hop: 1, ..FeeModel::default() };
+    assert_eq!(model.fee_for(0, 0).unwrap(), u64::MAX);
+    assert_eq!(model.fee_for(0, 1).unwrap_err(), OridionError::UnusualMathError.into());
+
+    let model = FeeModel { per_hop: u64::MAX, ..FeeModel::default() };
+    assert_eq!(model.fee_for(0, u64::MAX).unwrap_err(), OridionError::UnusualMathError.into());
+
+    // The cap applies before the conversion back to u64.
+    let capped = FeeModel { base: u64::MAX, bps: 10_000, max: 5_000, ..FeeModel::default() };
+    assert_eq!(capped.fee_for(u64::MAX, u64::MAX).unwrap(), 5_000);
+}
+
+fn pod_args(m: u8, d: u32) -> PodArgs {
+    PodArgs {
+        l: 1_000_000_000,
+        m,
+        d,
+        p: [1u8; 32],
+        de1: [2u8; 16],
+        de2: [3u8; 16],
+        au1: [4u8; 16],
+        au2: [5u8; 16],
+        s: false,
+    }
+}
+
+#[test]
+fn quote_matches_what_launch_charges() {
+    let now = 1_700_000_000;
+    let universe = Universe {
+        fees: Universe::flat_fees(3_000_000, 100_000),
+        delays: Universe::default_delays(),
+        ..Universe::default()
+    };
+
+    // `quote`, `launch`, `launch_private` and `schedule` all price the pod through `plan_launch`.
+    for (args, hops) in [(pod_args(1, 3_600), 20), (pod_args(2, 0), 1), (pod_args(3, 600), 0)] {
+        let plan = universe.plan_launch(&args, now).unwrap();
+        assert_eq!(plan.hops, hops);
+        assert_eq!(plan.fee, universe.fee_for(args.l, args.m, args.d).unwrap());
+        assert_eq!(plan.land_at, now + args.d as i64);
+    }
+    assert_eq!(universe.plan_launch(&pod_args(1, 3_600), now).unwrap().fee, 3_000_000 + 20 * 100_000);
+
+    // Rejected quotes are rejected launches.
+    let err = universe.plan_launch(&pod_args(1, 179), now).err().unwrap();
+    assert_eq!(err, OridionError::DelayTooShort.into());
+    let err = universe.plan_launch(&pod_args(4, 600), now).err().unwrap();
+    assert_eq!(err, OridionError::InvalidMode.into());
+    let err = universe.plan_launch(&PodArgs { p: [0u8; 32], ..pod_args(2, 0) }, now).err().unwrap();
+    assert_eq!(err, OridionError::InvalidPasscode.into());
+}
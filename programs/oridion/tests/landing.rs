@@ -0,0 +1,133 @@
+//! Regression tests: a pod can only ever be delivered once, whether it lands
+//! through the manager's `settle`, the creator's `user_settle` or a compliance refund.
+//! All three go through `LandBook::land_pod`.
+
+use anchor_lang::prelude::Pubkey;
+use oridion::account_land::LandBook;
+use oridion::account_pod::{Pod, PodLeg, PodLegs, PodState};
+use oridion::errors::OridionError;
+
+const NOW: i64 = 1_700_000_000;
+const KEY: Pubkey = Pubkey::new_from_array([5u8; 32]);
+
+fn ready_pod() -> Pod {
+    Pod {
+        id: 7,
+        mode: 1,
+        lamports: 1_000_000,
+        created_at: NOW - 600,
+        land_at: NOW,
+        state: PodState::ReadyToLand,
+        ..Pod::default()
+    }
+}
+
+fn book_with(pod: &Pod) -> LandBook {
+    LandBook { tickets: vec![pod.land_ticket(&KEY)] }
+}
+
+fn settle(pod: &mut Pod, book: &mut LandBook) -> anchor_lang::Result<()> {
+    book.land_pod(&KEY, pod, None, PodState::Landed, NOW)
+}
+
+fn user_settle(pod: &mut Pod, book: &mut LandBook) -> anchor_lang::Result<()> {
+    book.land_pod(&KEY, pod, None, PodState::Emergency, NOW)
+}
+
+#[test]
+fn settle_then_user_settle_is_rejected() {
+    let mut pod = ready_pod();
+    let mut book = book_with(&pod);
+
+    settle(&mut pod, &mut book).unwrap();
+    assert!(pod.is_landed());
+    assert!(book.tickets.is_empty());
+
+    let err = user_settle(&mut pod, &mut book).unwrap_err();
+    assert_eq!(err, OridionError::AlreadyLanded.into());
+}
+
+#[test]
+fn user_settle_then_settle_is_rejected() {
+    let mut pod = ready_pod();
+    let mut book = book_with(&pod);
+
+    user_settle(&mut pod, &mut book).unwrap();
+    assert!(book.tickets.is_empty(), "user_settle must clear the land ticket");
+
+    let err = settle(&mut pod, &mut book).unwrap_err();
+    assert_eq!(err, OridionError::AlreadyLanded.into());
+
+    // Even a fresh copy of the pod cannot reuse the consumed ticket.
+    let err = book.take(&ready_pod().land_ticket(&KEY)).unwrap_err();
+    assert_eq!(err, OridionError::TicketNotFound.into());
+}
+
+#[test]
+fn settle_twice_is_rejected() {
+    let mut pod = ready_pod();
+    let mut book = book_with(&pod);
+
+    settle(&mut pod, &mut book).unwrap();
+    let err = settle(&mut pod, &mut book).unwrap_err();
+    assert_eq!(err, OridionError::AlreadyLanded.into());
+}
+
+#[test]
+fn user_settle_before_ticket_leaves_other_tickets() {
+    let mut pod = ready_pod();
+    let other = Pubkey::new_unique();
+    let mut book = LandBook { tickets: vec![ready_pod().land_ticket(&other)] };
+
+    user_settle(&mut pod, &mut book).unwrap();
+    assert_eq!(book.tickets, vec![ready_pod().land_ticket(&other)]);
+}
+
+#[test]
+fn user_settle_clears_armed_leg_tickets() {
+    let mut pod = Pod { legs: 2, ..ready_pod() };
+    let leg = PodLeg { destination: [1u8; 32], lamports: 500_000, land_at: NOW, status: 0 };
+    let mut legs = PodLegs { legs: vec![leg, PodLeg { land_at: NOW + 3600, ..leg }], bump: 0 };
+    let mut book = LandBook { tickets: vec![] };
+
+    legs.arm_due(&KEY, &pod, &mut book, NOW, 240).unwrap();
+    assert_eq!(book.tickets.len(), 1, "only the due leg is armed");
+
+    // Split pods must bring their legs so the armed tickets can be found.
+    let err = user_settle(&mut pod.clone(), &mut book).unwrap_err();
+    assert_eq!(err, OridionError::PodLegsMissing.into());
+
+    book.land_pod(&KEY, &mut pod, Some(&legs), PodState::Emergency, NOW).unwrap();
+    assert!(book.tickets.is_empty());
+}
+
+#[test]
+fn refunds_clear_tickets_and_are_final() {
+    let mut pod = ready_pod();
+    let mut book = book_with(&pod);
+    pod.freeze(2, NOW + 100).unwrap();
+
+    book.land_pod(&KEY, &mut pod, None, PodState::Cancelled, NOW).unwrap();
+    assert_eq!((pod.state, pod.landed_at), (PodState::Cancelled, NOW));
+    assert!(book.tickets.is_empty());
+
+    let err = settle(&mut pod, &mut book).unwrap_err();
+    assert_eq!(err, OridionError::AlreadyLanded.into());
+}
+
+#[test]
+fn settle_needs_the_ticket_but_emergency_does_not() {
+    let mut book = LandBook { tickets: vec![] };
+    let mut pod = ready_pod();
+    let err = settle(&mut pod, &mut book).unwrap_err();
+    assert_eq!(err, OridionError::TicketNotFound.into());
+
+    user_settle(&mut ready_pod(), &mut book).unwrap();
+}
+
+#[test]
+fn tickets_are_unique_across_creators() {
+    // Same id, amount and launch time, but different pod addresses.
+    let pod = ready_pod();
+    assert_ne!(pod.land_ticket(&KEY), pod.land_ticket(&Pubkey::new_unique()));
+}
@@ -0,0 +1,119 @@
+//! Per-creator rolling volume limits tracked on `PodMeta`.
+
+use oridion::account_pod::{PodEntryStatus, PodMeta};
+use oridion::account_schedule::Schedule;
+use oridion::account_universe::{DepositLimits, Universe};
+use oridion::errors::OridionError;
+
+const DAY: i64 = 86_400;
+const NOW: i64 = 1_700_000_000;
+
+fn limits(volume: u64) -> DepositLimits {
+    DepositLimits { creator_volume: volume, creator_window: DAY as u32, ..DepositLimits::default() }
+}
+
+#[test]
+fn volume_is_capped_within_the_window() {
+    let mut meta = PodMeta::default();
+    let limits = limits(1_000);
+
+    meta.add_volume(600, NOW, &limits).unwrap();
+    meta.add_volume(400, NOW + DAY / 2, &limits).unwrap();
+    let err = meta.add_volume(1, NOW + DAY / 2, &limits).unwrap_err();
+    assert_eq!(err, OridionError::CreatorLimitExceeded.into());
+}
+
+#[test]
+fn old_volume_rolls_out_of_the_window() {
+    let mut meta = PodMeta::default();
+    let limits = limits(1_000);
+
+    meta.add_volume(600, NOW, &limits).unwrap();
+    meta.add_volume(400, NOW + DAY / 2, &limits).unwrap();
+
+    // The first deposit has left the window, the second has not.
+    meta.add_volume(600, NOW + DAY + DAY / 4, &limits).unwrap();
+    let err = meta.add_volume(1, NOW + DAY + DAY / 4, &limits).unwrap_err();
+    assert_eq!(err, OridionError::CreatorLimitExceeded.into());
+
+    // Long idle periods clear everything.
+    meta.add_volume(1_000, NOW + 10 * DAY, &limits).unwrap();
+}
+
+#[test]
+fn zero_limit_is_not_enforced() {
+    let mut meta = PodMeta::default();
+    meta.add_volume(u64::MAX, NOW, &limits(0)).unwrap();
+    assert!(limits(1).validate().is_ok());
+    assert_eq!(
+        DepositLimits { creator_volume: 1, ..DepositLimits::default() }.validate().unwrap_err(),
+        OridionError::InvalidLimits.into()
+    );
+}
+
+#[test]
+fn pod_meta_stays_open_while_volume_is_in_the_window() {
+    let mut meta = PodMeta::default();
+    let limits = limits(1_000);
+    assert!(meta.is_empty(NOW, &limits));
+
+    meta.add_volume(600, NOW, &limits).unwrap();
+    assert_eq!(meta.volume_in_window(NOW + DAY / 2, &limits), 600);
+    assert!(!meta.is_empty(NOW + DAY / 2, &limits));
+
+    assert_eq!(meta.volume_in_window(NOW + 2 * DAY, &limits), 0);
+    assert!(meta.is_empty(NOW + 2 * DAY, &limits));
+}
+
+#[test]
+fn used_pod_meta_closes_without_reusing_pod_ids() {
+    let mut universe = Universe::default();
+    let mut meta = PodMeta::default();
+    let first = universe.allocate_pod_id().unwrap();
+    meta.index.insert(first).unwrap();
+    assert!(!meta.is_empty(NOW, &limits(0)));
+
+    // Once the pod is reclaimed the meta may close and be opened again.
+    assert!(meta.index.set_status(first, PodEntryStatus::Closed));
+    assert!(meta.is_empty(NOW, &limits(0)));
+    let reopened = PodMeta::default();
+    assert!(reopened.is_empty(NOW, &limits(0)));
+
+    // Ids live on the universe, so the reopened meta's next pod gets a fresh PDA.
+    assert_eq!(universe.allocate_pod_id().unwrap(), first + 1);
+    universe.next_pod_id = u64::MAX;
+    assert_eq!(universe.allocate_pod_id().unwrap_err(), OridionError::UnusualMathError.into());
+}
+
+#[test]
+fn schedules_count_deposits_as_pods_spawn() {
+    let mut universe = Universe {
+        limits: DepositLimits { daily_inflow: 1_000, ..limits(10_000) },
+        ..Universe::default()
+    };
+    let mut meta = PodMeta::default();
+    // Two schedules worth far more than the daily cap hold none of it until they spawn.
+    let mut first = Schedule { periods: 5, lamports: 400, interval: 60, next_spawn_at: NOW, ..Schedule::default() };
+    let mut second = Schedule { periods: 5, lamports: 400, interval: 60, next_spawn_at: NOW, ..Schedule::default() };
+
+    assert_eq!(first.spawn_next(&mut meta, &mut universe, NOW).unwrap(), 0);
+    assert_eq!(second.spawn_next(&mut meta, &mut universe, NOW).unwrap(), 0);
+    assert_eq!((universe.inflow_today, meta.volume_in_window(NOW, &universe.limits)), (800, 800));
+
+    let err = first.spawn_next(&mut meta, &mut universe, NOW + 60).unwrap_err();
+    assert_eq!(err, OridionError::DailyInflowExceeded.into());
+    let err = second.spawn_next(&mut meta, &mut universe, NOW + 59).unwrap_err();
+    assert_eq!(err, OridionError::ScheduleNotDue.into());
+}
+
+#[test]
+fn private_launches_skip_the_meta_only_without_volume_limits() {
+    let mut universe = Universe::default();
+    universe.count_deposit(None, 400, NOW).unwrap();
+    assert_eq!(universe.inflow_today, 400);
+
+    universe.limits = limits(1_000);
+    let err = universe.count_deposit(None, 400, NOW).unwrap_err();
+    assert_eq!(err, OridionError::PodMetaMissing.into());
+    universe.count_deposit(Some(&mut PodMeta::default()), 400, NOW).unwrap();
+}
@@ -0,0 +1,93 @@
+//! Planet locks: hops lock atomically unless they carry a `lock_node` nonce.
+
+use anchor_lang::prelude::Pubkey;
+use oridion::account_planet::Planet;
+use oridion::errors::OridionError;
+
+const NOW: i64 = 1_700_000_000;
+const EXPIRY: i64 = 30;
+
+#[test]
+fn hops_without_nonce_lock_free_planets() {
+    let pod = Pubkey::new_unique();
+    let mut planet = Planet::default();
+
+    planet.enter(pod, None, NOW, EXPIRY).unwrap();
+    assert_eq!((planet.locked_by, planet.locked_at, planet.lock_nonce), (pod, NOW, 1));
+}
+
+#[test]
+fn live_locks_block_other_pods() {
+    let [holder, other] = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut planet = Planet::default();
+    planet.acquire_lock(holder, NOW, EXPIRY).unwrap();
+
+    let err = planet.enter(other, None, NOW + 1, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::PlanetStillLocked.into());
+    planet.enter(holder, None, NOW + 1, EXPIRY).unwrap();
+
+    // Expired locks can be taken over
+    assert_eq!(planet.acquire_lock(other, NOW + 60, EXPIRY).unwrap(), 3);
+    assert_eq!(planet.locked_by, other);
+}
+
+#[test]
+fn nonce_must_match_the_held_lock() {
+    let [pod, other] = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut planet = Planet::default();
+    let nonce = planet.acquire_lock(pod, NOW, EXPIRY).unwrap();
+
+    planet.enter(pod, Some(nonce), NOW + 5, EXPIRY).unwrap();
+    let err = planet.enter(pod, Some(nonce + 1), NOW + 5, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::LockNonceMismatch.into());
+    let err = planet.enter(other, Some(nonce), NOW + 5, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::NotAuthorizedToHop.into());
+    let err = planet.enter(pod, Some(nonce), NOW + 60, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::LockExpired.into());
+
+    // A lock taken over after expiry invalidates the old nonce
+    planet.acquire_lock(other, NOW + 60, EXPIRY).unwrap();
+    planet.acquire_lock(pod, NOW + 120, EXPIRY).unwrap();
+    let err = planet.enter(pod, Some(nonce), NOW + 121, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::LockNonceMismatch.into());
+}
+
+#[test]
+fn locks_expire_exactly_at_the_configured_age() {
+    let [pod, other] = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut planet = Planet::default();
+    let nonce = planet.acquire_lock(pod, NOW, EXPIRY).unwrap();
+
+    // Live for the holder and closed to others up to the last second
+    assert!(planet.lock_live(NOW + EXPIRY - 1, EXPIRY));
+    planet.enter(pod, Some(nonce), NOW + EXPIRY - 1, EXPIRY).unwrap();
+    assert!(planet.try_lock(other, NOW + EXPIRY - 1, EXPIRY).is_none());
+
+    // Expired for both at the boundary
+    assert!(!planet.lock_live(NOW + EXPIRY, EXPIRY));
+    let err = planet.enter(pod, Some(nonce), NOW + EXPIRY, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::LockExpired.into());
+    assert!(planet.try_lock(other, NOW + EXPIRY, EXPIRY).is_some());
+
+    // A longer configured expiry keeps the same lock live
+    assert!(planet.lock_live(NOW + EXPIRY + 10, 120));
+}
+
+#[test]
+fn lock_counters_track_acquisitions_expiries_and_contention() {
+    let [pod, other] = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut planet = Planet::default();
+
+    planet.try_lock(pod, NOW, EXPIRY).unwrap();
+    assert!(planet.try_lock(other, NOW + 1, EXPIRY).is_none());
+    planet.try_lock(other, NOW + EXPIRY, EXPIRY).unwrap();
+
+    assert_eq!(planet.lock_acquired, 2);
+    assert_eq!(planet.pre_lock_contended, 1);
+
+    // A lock taken by the moving instruction itself reverts on contention and is not counted.
+    let err = planet.acquire_lock(pod, NOW + EXPIRY + 1, EXPIRY).unwrap_err();
+    assert_eq!(err, OridionError::PlanetStillLocked.into());
+    assert_eq!(planet.pre_lock_contended, 1);
+    assert_eq!(planet.lock_expired, 1);
+}
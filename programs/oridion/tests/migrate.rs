@@ -0,0 +1,104 @@
+//! Pre-upgrade accounts are rewritten into the current layouts without losing state.
+
+use anchor_lang::prelude::Pubkey;
+use oridion::account_migrate::{LegacyPlanet, LegacyPod, LegacyPodMeta, LegacyStar, LegacyTreasury, LegacyUniverse};
+use oridion::account_pod::PodState;
+use oridion::errors::OridionError;
+
+fn legacy_pod(next_process: u8, last_process: u8) -> LegacyPod {
+    LegacyPod {
+        account_type: 1,
+        version: 1,
+        mode: 1,
+        next_process,
+        last_process,
+        is_in_transit: 0,
+        id: 7,
+        hops: 3,
+        delay: 3_600,
+        next_process_at: 1_700_000_300,
+        land_at: 1_700_003_600,
+        created_at: 1_700_000_000,
+        last_process_at: 1_700_000_200,
+        lamports: 1_000_000,
+        location: Pubkey::new_unique(),
+        destination: [4u8; 32],
+        passcode_hash: [5u8; 32],
+        authority: [6u8; 32],
+    }
+}
+
+#[test]
+fn universe_keeps_its_fee_schedule() {
+    let legacy = LegacyUniverse { account_type: 0, locked: 1, bump: 254, created: 1, last_updated: 2, fee: 3_000, increment: 100 };
+    let universe = legacy.upgrade();
+    assert_eq!((universe.locked, universe.bump, universe.created), (1, 254, 1));
+    assert_eq!(universe.fee_for(10_000, 1, 180 * 4).unwrap(), legacy.fee + 4 * legacy.increment);
+    assert_eq!(universe.fee_for(10_000, 2, 0).unwrap(), legacy.fee);
+    universe.delay_bounds(1).unwrap().validate(1).unwrap();
+}
+
+#[test]
+fn pod_state_follows_the_legacy_process_flags() {
+    let key = Pubkey::new_unique();
+    let cases = [
+        (legacy_pod(0, 0), false, PodState::Launched),
+        (legacy_pod(1, 0), false, PodState::Launched),
+        (legacy_pod(0, 1), false, PodState::Hopping),
+        (legacy_pod(1, 1), true, PodState::ReadyToLand),
+        (legacy_pod(1, 1), false, PodState::Landed),
+    ];
+    for (legacy, ticketed, state) in cases {
+        let pod = legacy.upgrade(&key, ticketed).unwrap();
+        assert_eq!(pod.state, state);
+        assert_eq!(pod.is_landed(), state == PodState::Landed);
+        assert_eq!((pod.id, pod.lamports, pod.location), (7, legacy.lamports, legacy.location));
+        assert!(!pod.indexed);
+    }
+    assert_ne!(legacy_pod(1, 1).land_ticket(), legacy_pod(1, 1).upgrade(&key, true).unwrap().land_ticket(&key));
+
+    let in_transit = LegacyPod { is_in_transit: 1, ..legacy_pod(0, 1) };
+    let err = in_transit.upgrade(&key, false).err().unwrap();
+    assert_eq!(err, OridionError::InTransit.into());
+}
+
+#[test]
+fn pod_meta_starts_with_an_empty_index() {
+    let meta = LegacyPodMeta { ids: vec![1, 2, 3], created_at: 9 }.upgrade();
+    assert_eq!((meta.created_at, meta.pages), (9, 0));
+    assert!(meta.index.is_empty());
+}
+
+#[test]
+fn treasury_keeps_its_authority_with_nothing_queued() {
+    let authority = Pubkey::new_unique();
+    let treasury = LegacyTreasury { bump: 252, authority }.upgrade();
+    assert_eq!((treasury.bump, treasury.authority), (252, authority));
+    assert_eq!((treasury.config_after, treasury.pending_authority), (0, Pubkey::default()));
+}
+
+#[test]
+fn free_stars_join_the_pool() {
+    let star = LegacyStar { amount: 0, manager: Pubkey::new_unique() }.upgrade(253).unwrap();
+    assert_eq!((star.held_by, star.bump), (Pubkey::default(), 253));
+    let busy = LegacyStar { amount: 1, manager: Pubkey::new_unique() }.upgrade(253);
+    assert_eq!(busy.err().unwrap(), OridionError::StarInUse.into());
+}
+
+#[test]
+fn planet_keeps_its_lock_and_visits() {
+    let locked_by = Pubkey::new_unique();
+    let legacy = LegacyPlanet {
+        account_type: 2,
+        bump: 250,
+        created: 1,
+        visits: 40,
+        base_lamports: 5_000,
+        locked_at: 77,
+        locked_by,
+        name: "mars".into(),
+    };
+    let planet = legacy.upgrade();
+    assert_eq!((planet.visits, planet.locked_at, planet.locked_by, planet.name.as_str()), (40, 77, locked_by, "mars"));
+    assert_eq!(planet.lock_nonce, 0);
+}
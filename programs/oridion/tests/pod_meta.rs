@@ -0,0 +1,63 @@
+//! The per-creator pod index never evicts open pods.
+
+use oridion::account_pod::{mark_index_entry, PodEntryStatus, PodIndex};
+use oridion::errors::OridionError;
+
+const MAX: u64 = 50;
+
+fn full_index() -> PodIndex {
+    let mut index = PodIndex::default();
+    for id in 0..MAX {
+        index.insert(id).unwrap();
+    }
+    index
+}
+
+#[test]
+fn full_index_raises_too_many_pods() {
+    let mut index = full_index();
+    assert!(!index.has_room());
+
+    let err = index.insert(MAX).unwrap_err();
+    assert_eq!(err, OridionError::TooManyPods.into());
+    assert!(index.entries.iter().all(|e| e.id < MAX && e.status == PodEntryStatus::Active));
+}
+
+#[test]
+fn landed_pods_keep_their_slot() {
+    let mut index = full_index();
+    assert!(index.set_status(3, PodEntryStatus::Landed));
+    assert_eq!(index.insert(MAX).unwrap_err(), OridionError::TooManyPods.into());
+}
+
+#[test]
+fn closed_slots_are_reused() {
+    let mut index = full_index();
+    assert!(index.set_status(7, PodEntryStatus::Closed));
+    assert!(index.has_room());
+
+    index.insert(MAX).unwrap();
+    assert_eq!(index.entries.len(), MAX as usize);
+    assert!(!index.entries.iter().any(|e| e.id == 7));
+    assert!(!index.set_status(7, PodEntryStatus::Landed), "closed entries are gone");
+}
+
+#[test]
+fn indexed_pods_must_find_their_entry() {
+    let mut meta = full_index();
+    let mut page = PodIndex::default();
+    page.insert(MAX).unwrap();
+
+    let err = mark_index_entry(true, None, Some(&mut page), MAX, PodEntryStatus::Closed).unwrap_err();
+    assert_eq!(err, OridionError::PodMetaMissing.into());
+    let err = mark_index_entry(true, Some(&mut meta), None, MAX, PodEntryStatus::Closed).unwrap_err();
+    assert_eq!(err, OridionError::PodEntryNotFound.into());
+
+    mark_index_entry(true, Some(&mut meta), Some(&mut page), MAX, PodEntryStatus::Closed).unwrap();
+    assert!(page.is_empty());
+    let err = mark_index_entry(true, Some(&mut meta), Some(&mut page), MAX, PodEntryStatus::Closed).unwrap_err();
+    assert_eq!(err, OridionError::PodEntryNotFound.into(), "closed entries cannot be marked again");
+
+    // Private and scheduled pods have no entry to mark.
+    mark_index_entry(false, None, None, MAX, PodEntryStatus::Closed).unwrap();
+}
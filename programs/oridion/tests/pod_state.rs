@@ -0,0 +1,135 @@
+//! The pod lifecycle transition table.
+
+use oridion::account_pod::{Pod, PodState, PodState::*};
+use oridion::errors::OridionError;
+
+const ALL: [PodState; 9] = [
+    Launched,
+    Hopping,
+    InTransitStar,
+    InTransitScatter,
+    ReadyToLand,
+    Landed,
+    Cancelled,
+    Emergency,
+    Frozen,
+];
+
+#[test]
+fn happy_paths_are_allowed() {
+    let paths: &[&[PodState]] = &[
+        &[Launched, Hopping, Hopping, ReadyToLand, Landed],
+        &[Launched, InTransitStar, Hopping, InTransitScatter, ReadyToLand, Landed],
+        &[Launched, InTransitScatter, Hopping, InTransitStar, ReadyToLand, Landed],
+        &[Launched, Hopping, Emergency],
+        &[Launched, Hopping, ReadyToLand, Cancelled],
+    ];
+    for path in paths {
+        let mut pod = Pod { state: path[0], ..Pod::default() };
+        for to in &path[1..] {
+            pod.transition(*to).unwrap_or_else(|_| panic!("{:?} -> {:?}", pod.state, to));
+        }
+    }
+}
+
+#[test]
+fn final_states_are_terminal() {
+    for from in [Landed, Cancelled, Emergency] {
+        for to in ALL {
+            assert!(!from.can_transition(to), "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn in_transit_pods_must_finish_the_hop() {
+    for from in [InTransitStar, InTransitScatter] {
+        for to in [InTransitStar, InTransitScatter, Landed, Emergency, Cancelled] {
+            let mut pod = Pod { state: from, ..Pod::default() };
+            let err = pod.transition(to).unwrap_err();
+            assert_eq!(err, OridionError::InvalidPodTransition.into());
+            assert_eq!(pod.state, from);
+        }
+    }
+}
+
+#[test]
+fn in_transit_pods_cannot_start_another_hop() {
+    // `route1`, `route_batch` and every `*_start` require `can_hop`; only the matching `*_end`
+    // may move an in-transit pod, releasing the stars or scatter planets holding its funds.
+    for from in [InTransitStar, InTransitScatter] {
+        assert!(!from.can_hop(), "{:?} must not start a hop", from);
+        assert!(from.is_in_transit());
+    }
+    for from in ALL {
+        assert_eq!(from.can_hop(), matches!(from, Launched | Hopping), "{:?}", from);
+    }
+}
+
+#[test]
+fn ready_to_land_pods_cannot_hop_again() {
+    for to in [Hopping, InTransitStar, InTransitScatter, ReadyToLand] {
+        assert!(!ReadyToLand.can_transition(to), "ReadyToLand -> {:?}", to);
+    }
+}
+
+#[test]
+fn only_ready_pods_land() {
+    for from in [Launched, Hopping, InTransitStar, InTransitScatter] {
+        let mut pod = Pod { state: from, ..Pod::default() };
+        let err = pod.land(Landed, 1).unwrap_err();
+        assert_eq!(err, OridionError::InvalidPodTransition.into());
+        assert!(!pod.is_landed());
+    }
+}
+
+#[test]
+fn frozen_pods_cannot_hop_or_land() {
+    for to in [InTransitStar, InTransitScatter, Landed, Emergency] {
+        assert!(!Frozen.can_transition(to), "Frozen -> {:?}", to);
+    }
+    // Frozen -> Hopping | ReadyToLand only serves releases; hops refuse frozen pods.
+    assert!(!Frozen.can_hop());
+    assert!(!Frozen.is_in_transit());
+    for from in [InTransitStar, InTransitScatter] {
+        let mut pod = Pod { state: from, ..Pod::default() };
+        assert_eq!(pod.freeze(1, 100).unwrap_err(), OridionError::InvalidPodTransition.into());
+    }
+}
+
+#[test]
+fn release_restores_state_and_respects_expiry() {
+    let mut pod = Pod { state: ReadyToLand, ..Pod::default() };
+    pod.freeze(3, 100).unwrap();
+    assert_eq!(pod.state, Frozen);
+
+    let err = pod.release(false, 99).unwrap_err();
+    assert_eq!(err, OridionError::FreezeActive.into());
+    pod.release(false, 100).unwrap();
+    assert_eq!(pod.state, ReadyToLand);
+    assert_eq!(pod.freeze_reason, 3);
+
+    pod.freeze(4, 100).unwrap();
+    pod.release(true, 0).unwrap();
+    assert_eq!(pod.release(true, 0).unwrap_err(), OridionError::PodNotFrozen.into());
+
+    // Releases go through the transition table like any other move.
+    for from in [Launched, Hopping] {
+        let mut pod = Pod { state: from, ..Pod::default() };
+        pod.freeze(1, 100).unwrap();
+        pod.release(true, 0).unwrap();
+        assert_eq!(pod.state, from);
+    }
+    let mut pod = Pod { state: Frozen, frozen_from: Landed, ..Pod::default() };
+    assert_eq!(pod.release(true, 0).unwrap_err(), OridionError::InvalidPodTransition.into());
+    assert_eq!(pod.state, Frozen);
+}
+
+#[test]
+fn refunded_pods_are_final() {
+    let mut pod = Pod { state: Hopping, ..Pod::default() };
+    pod.freeze(2, 100).unwrap();
+    pod.land(Cancelled, 50).unwrap();
+    assert!(pod.is_landed());
+    assert!(pod.state.is_final());
+}
@@ -0,0 +1,55 @@
+//! Ownership proofs for private (commitment-seeded) and scheduled pods.
+
+use anchor_lang::prelude::Pubkey;
+use oridion::account_pod::Pod;
+use oridion::errors::OridionError;
+
+const SECRET: [u8; 32] = [9u8; 32];
+
+#[test]
+fn secret_opens_commitment_for_owner_only() {
+    let owner = Pubkey::new_unique();
+    let commitment = Pod::commitment(&SECRET, &owner);
+    let pod = Pod::default();
+
+    pod.require_owner(&commitment, Some(SECRET), &owner).unwrap();
+
+    // Replaying the revealed secret from another wallet fails.
+    let err = pod.require_owner(&commitment, Some(SECRET), &Pubkey::new_unique()).unwrap_err();
+    assert_eq!(err, OridionError::InvalidPodProof.into());
+
+    let err = pod.require_owner(&commitment, Some([1u8; 32]), &owner).unwrap_err();
+    assert_eq!(err, OridionError::InvalidPodProof.into());
+}
+
+#[test]
+fn authority_signature_proves_ownership() {
+    let owner = Pubkey::new_unique();
+    let commitment = Pod::commitment(&SECRET, &owner);
+    let pod = Pod { authority: owner.to_bytes(), ..Pod::default() };
+
+    pod.require_owner(&commitment, None, &owner).unwrap();
+    let err = pod.require_owner(&commitment, None, &Pubkey::new_unique()).unwrap_err();
+    assert_eq!(err, OridionError::InvalidPodProof.into());
+
+    // Without an authority only the secret can prove ownership.
+    let err = Pod::default().require_owner(&commitment, None, &Pubkey::default()).unwrap_err();
+    assert_eq!(err, OridionError::InvalidPodProof.into());
+}
+
+#[test]
+fn schedule_creator_or_authority_owns_spawned_pods() {
+    let creator = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let key = Pubkey::new_unique();
+    let pod = Pod { source: Pod::source_hash(&creator, &key), ..Pod::default() };
+
+    pod.require_scheduled_owner(&key, &creator).unwrap();
+    let err = pod.require_scheduled_owner(&Pubkey::new_unique(), &creator).unwrap_err();
+    assert_eq!(err, OridionError::InvalidPodProof.into());
+    let err = pod.require_scheduled_owner(&key, &authority).unwrap_err();
+    assert_eq!(err, OridionError::InvalidPodProof.into());
+
+    let pod = Pod { authority: authority.to_bytes(), ..pod };
+    pod.require_scheduled_owner(&key, &authority).unwrap();
+}
@@ -0,0 +1,177 @@
+//! Treasury payouts keep rent, respect the epoch cap and the timelock threshold.
+
+use anchor_lang::prelude::Pubkey;
+use oridion::account_treasury::{FeeSplit, Partner, PayoutLimits, Treasury};
+use oridion::errors::OridionError;
+
+const FLOOR: u64 = 1_000;
+
+fn treasury(limits: PayoutLimits) -> Treasury {
+    Treasury { limits, ..Treasury::default() }
+}
+
+#[test]
+fn payouts_never_touch_the_rent_floor() {
+    let mut t = treasury(PayoutLimits::default());
+    t.check_payout(4_000, 5_000, FLOOR, 1, false).unwrap();
+    let err = t.check_payout(4_001, 5_000, FLOOR, 1, false).unwrap_err();
+    assert_eq!(err, OridionError::InsufficientTreasuryBalance.into());
+    let err = t.check_payout(4_001, 5_000, FLOOR, 1, true).unwrap_err();
+    assert_eq!(err, OridionError::InsufficientTreasuryBalance.into());
+}
+
+#[test]
+fn epoch_cap_resets_each_epoch() {
+    let mut t = treasury(PayoutLimits { epoch_cap: 500, ..PayoutLimits::default() });
+    t.check_payout(300, u64::MAX, FLOOR, 7, false).unwrap();
+    t.check_payout(200, u64::MAX, FLOOR, 7, false).unwrap();
+    let err = t.check_payout(1, u64::MAX, FLOOR, 7, false).unwrap_err();
+    assert_eq!(err, OridionError::PayoutCapExceeded.into());
+
+    t.check_payout(500, u64::MAX, FLOOR, 8, false).unwrap();
+    assert_eq!(t.paid_in_epoch, 500);
+}
+
+#[test]
+fn large_payouts_need_the_timelock() {
+    let limits = PayoutLimits { epoch_cap: 100, timelock_above: 50, timelock_delay: 3_600 };
+    limits.validate().unwrap();
+    let mut t = treasury(limits);
+
+    let err = t.check_payout(51, u64::MAX, FLOOR, 1, false).unwrap_err();
+    assert_eq!(err, OridionError::PayoutNeedsTimelock.into());
+    t.check_payout(10_000, u64::MAX, FLOOR, 1, true).unwrap();
+    assert_eq!(t.paid_in_epoch, 0, "timelocked payouts skip the epoch cap");
+
+    let no_delay = PayoutLimits { timelock_delay: 0, ..limits };
+    assert_eq!(no_delay.validate().unwrap_err(), OridionError::InvalidPayoutLimits.into());
+}
+
+fn split() -> FeeSplit {
+    FeeSplit {
+        operator: Pubkey::new_from_array([1u8; 32]),
+        insurance: Pubkey::new_from_array([2u8; 32]),
+        partner: Pubkey::new_from_array([3u8; 32]),
+        operator_bps: 2_000,
+        insurance_bps: 1_000,
+        partner_bps: 500,
+    }
+}
+
+#[test]
+fn fee_split_shares_round_down_and_are_reserved() {
+    let mut t = treasury(PayoutLimits::default());
+    t.split = split();
+    t.split.validate().unwrap();
+    assert_eq!(t.split.shares(10_001), [2_000, 1_000, 500]);
+
+    t.accrue(10_000).unwrap();
+    assert_eq!(t.reserved(), 3_500);
+    let err = t.check_payout(6_501, 10_000 + FLOOR, FLOOR, 1, false).unwrap_err();
+    assert_eq!(err, OridionError::InsufficientTreasuryBalance.into());
+    t.check_payout(6_500, 10_000 + FLOOR, FLOOR, 1, false).unwrap();
+}
+
+#[test]
+fn fee_split_rejects_bad_tables() {
+    let over = FeeSplit { partner_bps: 7_001, ..split() };
+    assert_eq!(over.validate().unwrap_err(), OridionError::InvalidFeeSplit.into());
+    let unset = FeeSplit { partner: Pubkey::default(), ..split() };
+    assert_eq!(unset.validate().unwrap_err(), OridionError::InvalidFeeSplit.into());
+    FeeSplit { partner: Pubkey::default(), partner_bps: 0, ..split() }.validate().unwrap();
+}
+
+#[test]
+fn partners_earn_their_share_while_active() {
+    let mut partner = Partner { bps: 2_500, active: true, ..Partner::default() };
+    assert_eq!(partner.share_of(10_003), 2_500);
+
+    partner.accrue(2_500).unwrap();
+    partner.accrue(100).unwrap();
+    assert_eq!((partner.accrued, partner.earned), (2_600, 2_600));
+
+    partner.active = false;
+    assert_eq!(partner.share_of(10_000), 0);
+}
+
+#[test]
+fn relaxed_limits_wait_out_the_timelock() {
+    let limits = PayoutLimits { epoch_cap: 100, timelock_above: 50, timelock_delay: 3_600 };
+    let guardian = Pubkey::new_from_array([9u8; 32]);
+    let mut t = Treasury { guardian, ..treasury(limits) };
+
+    let open = PayoutLimits { epoch_cap: 0, timelock_above: 0, timelock_delay: 3_600 };
+    assert!(!t.propose_config(guardian, open, 1_000).unwrap());
+    assert_eq!(t.config_after, 4_600);
+    assert_eq!(t.apply_config(4_599).unwrap_err(), OridionError::PayoutLocked.into());
+    let err = t.check_payout(51, u64::MAX, FLOOR, 1, false).unwrap_err();
+    assert_eq!(err, OridionError::PayoutNeedsTimelock.into());
+
+    t.apply_config(4_600).unwrap();
+    assert_eq!(t.limits.epoch_cap, 0);
+    t.check_payout(10_000, u64::MAX, FLOOR, 1, false).unwrap();
+    assert_eq!(t.apply_config(9_999).unwrap_err(), OridionError::NoPendingConfig.into());
+}
+
+#[test]
+fn tighter_limits_apply_at_once_but_guardian_changes_queue() {
+    let limits = PayoutLimits { epoch_cap: 100, timelock_above: 50, timelock_delay: 3_600 };
+    let guardian = Pubkey::new_from_array([9u8; 32]);
+    let mut t = Treasury { guardian, ..treasury(limits) };
+
+    let stricter = PayoutLimits { epoch_cap: 10, timelock_above: 5, timelock_delay: 7_200 };
+    assert!(t.propose_config(guardian, stricter, 1_000).unwrap());
+    assert_eq!((t.limits.epoch_cap, t.config_after), (10, 0));
+
+    let other = Pubkey::new_from_array([8u8; 32]);
+    assert!(!t.propose_config(other, stricter, 1_000).unwrap());
+    assert_eq!(t.guardian, guardian);
+    t.cancel_config().unwrap();
+    assert_eq!(t.apply_config(i64::MAX).unwrap_err(), OridionError::NoPendingConfig.into());
+    assert_eq!(t.guardian, guardian);
+}
+
+#[test]
+fn raised_fee_split_cannot_be_distributed_before_the_timelock() {
+    let limits = PayoutLimits { epoch_cap: 100, timelock_above: 50, timelock_delay: 3_600 };
+    let guardian = Pubkey::new_from_array([9u8; 32]);
+    let mut t = Treasury { guardian, split: split(), ..treasury(limits) };
+    t.accrue(10_000).unwrap();
+
+    // The authority points the whole fee stream at itself.
+    let sweep = FeeSplit { operator: Pubkey::new_from_array([7u8; 32]), operator_bps: 10_000, insurance_bps: 0, partner_bps: 0, ..split() };
+    assert!(!t.propose_split(sweep, 1_000).unwrap());
+    assert_eq!(t.config_after, 4_600);
+    assert_eq!(t.split.shares(t.undistributed), [2_000, 1_000, 500], "distribute still uses the live split");
+    assert_eq!(t.apply_config(4_599).unwrap_err(), OridionError::PayoutLocked.into());
+
+    // The guardian vetoes it; the live split never changed.
+    t.cancel_config().unwrap();
+    assert_eq!(t.apply_config(i64::MAX).unwrap_err(), OridionError::NoPendingConfig.into());
+    assert_eq!(t.split.operator_bps, 2_000);
+
+    // Lower shares to the same recipients apply at once and are kept by a queued change.
+    let open = PayoutLimits { epoch_cap: 0, ..limits };
+    assert!(!t.propose_config(guardian, open, 1_000).unwrap());
+    let lower = FeeSplit { operator_bps: 1_000, ..split() };
+    assert!(t.propose_split(lower, 1_000).unwrap());
+    t.apply_config(4_600).unwrap();
+    assert_eq!((t.split.operator_bps, t.limits.epoch_cap), (1_000, 0));
+}
+
+#[test]
+fn authority_transfers_wait_out_the_timelock() {
+    let limits = PayoutLimits { timelock_delay: 3_600, ..PayoutLimits::default() };
+    let authority = Pubkey::new_from_array([1u8; 32]);
+    let next = Pubkey::new_from_array([2u8; 32]);
+    let mut t = Treasury { authority, ..treasury(limits) };
+
+    t.propose_authority(next, 1_000).unwrap();
+    assert_eq!(t.accept_authority(4_599).unwrap_err(), OridionError::PayoutLocked.into());
+    t.cancel_config().unwrap();
+    assert_eq!(t.pending_authority, Pubkey::default());
+
+    t.propose_authority(next, 1_000).unwrap();
+    assert_eq!(t.accept_authority(4_600).unwrap(), authority);
+    assert_eq!((t.authority, t.pending_authority), (next, Pubkey::default()));
+}
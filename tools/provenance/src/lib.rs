@@ -0,0 +1,215 @@
+//! Offline provenance reports for disclosed Oridion pods.
+//!
+//! A disclosure envelope is `ephemeral_pub (32) || ChaCha20-Poly1305(DisclosureMeta)`.
+//! The key is `sha256(domain || x25519(ephemeral, recipient) || ephemeral_pub || recipient_pub)`;
+//! every envelope uses a fresh ephemeral key, so the nonce is fixed at zero.
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, AnchorSerialize};
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use oridion::account_pod::{DisclosureMeta, Pod, PodDisclosure};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const DOMAIN: &[u8] = b"ORIDION_DISCLOSE_V1";
+pub const ENVELOPE_LEN: usize = 32 + 80 + 16;
+
+/// x25519 public key for a viewing (or compliance) secret.
+pub fn public_key(secret: &[u8; 32]) -> [u8; 32] {
+    MontgomeryPoint::mul_base_clamped(*secret).to_bytes()
+}
+
+fn cipher(shared: MontgomeryPoint, ephemeral: &[u8; 32], recipient: &[u8; 32]) -> ChaCha20Poly1305 {
+    let key = Sha256::new()
+        .chain_update(DOMAIN)
+        .chain_update(shared.to_bytes())
+        .chain_update(ephemeral)
+        .chain_update(recipient)
+        .finalize();
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+/// Encrypts `meta` to `recipient`. `ephemeral` must be fresh random bytes for every envelope.
+pub fn seal(recipient: &[u8; 32], ephemeral: [u8; 32], meta: &DisclosureMeta) -> Vec<u8> {
+    let ephemeral_pub = public_key(&ephemeral);
+    let shared = MontgomeryPoint(*recipient).mul_clamped(ephemeral);
+    let plain = meta.try_to_vec().expect("borsh");
+    let sealed = cipher(shared, &ephemeral_pub, recipient)
+        .encrypt(Nonce::from_slice(&[0u8; 12]), plain.as_slice())
+        .expect("encrypt");
+
+    let mut envelope = ephemeral_pub.to_vec();
+    envelope.extend(sealed);
+    envelope
+}
+
+/// Opens an envelope with a viewing secret. `None` if it was not sealed to this key.
+pub fn open(secret: &[u8; 32], envelope: &[u8]) -> Option<DisclosureMeta> {
+    if envelope.len() != ENVELOPE_LEN {
+        return None;
+    }
+    let ephemeral_pub: [u8; 32] = envelope[..32].try_into().ok()?;
+    let shared = MontgomeryPoint(ephemeral_pub).mul_clamped(*secret);
+    let plain = cipher(shared, &ephemeral_pub, &public_key(secret))
+        .decrypt(Nonce::from_slice(&[0u8; 12]), &envelope[32..])
+        .ok()?;
+    DisclosureMeta::try_from_slice(&plain).ok()
+}
+
+/// One account as printed by `solana account <address> --output json`.
+#[derive(Deserialize)]
+pub struct AccountDump {
+    pub pubkey: String,
+    pub account: AccountData,
+}
+
+#[derive(Deserialize)]
+pub struct AccountData {
+    pub data: (String, String),
+}
+
+/// One transaction: its signature, slot, account keys and program log lines.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct TransactionLog {
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub block_time: Option<i64>,
+    pub accounts: Vec<String>,
+    pub logs: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct ActivityEntry {
+    pub action: String,
+    pub timestamp: i64,
+    pub slot: u64,
+}
+
+#[derive(Serialize)]
+pub struct PodReport {
+    pub pod: String,
+    pub creator: String,
+    pub destination: String,
+    pub lamports: u64,
+    pub created_at: i64,
+    /// `None` once the pod account has been closed.
+    pub state: Option<String>,
+    pub landed_at: Option<i64>,
+    /// Disclosed fields that disagree with the pod account. A mismatch means the disclosure cannot be trusted.
+    pub mismatches: Vec<String>,
+    /// Every disclosed field was checked against the pod and matched. False for closed pods, sealed
+    /// destinations (the reveal salt is not disclosed) and split pods (the amount drains leg by leg).
+    pub verified: bool,
+    pub activity: Vec<ActivityEntry>,
+    pub transactions: Vec<TransactionLog>,
+}
+
+#[derive(Debug)]
+pub enum ReportError {
+    Decode(String),
+}
+
+impl std::fmt::Display for ReportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportError::Decode(why) => write!(f, "decode error: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for ReportError {}
+
+fn decode(dump: &AccountDump) -> Result<(Pubkey, Vec<u8>), ReportError> {
+    let key = Pubkey::from_str(&dump.pubkey).map_err(|e| ReportError::Decode(e.to_string()))?;
+    let (data, encoding) = &dump.account.data;
+    if encoding != "base64" {
+        return Err(ReportError::Decode(format!("{}: unsupported encoding {encoding}", dump.pubkey)));
+    }
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map_err(|e| ReportError::Decode(e.to_string()))?;
+    Ok((key, bytes))
+}
+
+/// Checks a decrypted disclosure against the pod it was attached to.
+/// Returns the mismatched fields and whether every field could be checked.
+pub fn check_meta(meta: &DisclosureMeta, key: &Pubkey, pod: &Pod) -> (Vec<String>, bool) {
+    let mut mismatches = Vec::new();
+    if Pod::source_hash(&meta.creator, key) != pod.source {
+        mismatches.push("creator".to_string());
+    }
+    if meta.created_at != pod.created_at {
+        mismatches.push("created_at".to_string());
+    }
+    if pod.legs == 0 && meta.lamports != pod.lamports {
+        mismatches.push("lamports".to_string());
+    }
+    if !pod.sealed && meta.destination.to_bytes() != pod.destination {
+        mismatches.push("destination".to_string());
+    }
+    (mismatches, pod.legs == 0 && !pod.sealed)
+}
+
+/// Builds a report for every disclosure `secret` can open.
+/// Pods and disclosures are told apart by their account discriminators; anything else is skipped.
+pub fn build_report(
+    secret: &[u8; 32],
+    accounts: &[AccountDump],
+    transactions: &[TransactionLog],
+) -> Result<Vec<PodReport>, ReportError> {
+    let mut pods = Vec::new();
+    let mut disclosures = Vec::new();
+    for dump in accounts {
+        let (key, bytes) = decode(dump)?;
+        if let Ok(pod) = Pod::try_deserialize(&mut bytes.as_slice()) {
+            pods.push((key, pod));
+        } else if let Ok(disclosure) = PodDisclosure::try_deserialize(&mut bytes.as_slice()) {
+            disclosures.push(disclosure);
+        }
+    }
+
+    let mut reports = Vec::new();
+    for disclosure in disclosures {
+        let Some(meta) = open(secret, &disclosure.viewer).or_else(|| open(secret, &disclosure.compliance)) else {
+            continue;
+        };
+        let pod_key = disclosure.pod.to_string();
+        let pod = pods.iter().find(|(key, _)| *key == disclosure.pod).map(|(_, pod)| pod);
+        let (mismatches, complete) = match pod {
+            Some(pod) => check_meta(&meta, &disclosure.pod, pod),
+            None => (Vec::new(), false),
+        };
+        reports.push(PodReport {
+            creator: meta.creator.to_string(),
+            destination: meta.destination.to_string(),
+            lamports: meta.lamports,
+            created_at: meta.created_at,
+            state: pod.map(|pod| format!("{:?}", pod.state)),
+            landed_at: pod.map(|pod| pod.landed_at).filter(|at| *at != 0),
+            verified: complete && mismatches.is_empty(),
+            mismatches,
+            activity: pod
+                .map(|pod| {
+                    pod.activity_log()
+                        .into_iter()
+                        .map(|a| ActivityEntry { action: format!("{:?}", a.action), timestamp: a.timestamp, slot: a.slot })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            transactions: transactions
+                .iter()
+                .filter(|tx| tx.accounts.contains(&pod_key))
+                .cloned()
+                .collect(),
+            pod: pod_key,
+        });
+    }
+    reports.sort_by_key(|report| report.created_at);
+    Ok(reports)
+}
@@ -0,0 +1,47 @@
+//! provenance --key <viewing secret hex> --accounts <accounts.json> [--logs <transactions.json>]
+//!
+//! `accounts.json` is an array of `solana account <address> --output json` dumps
+//! (pods and pod disclosures). `transactions.json` is an optional array of
+//! `{ signature, slot, block_time, accounts, logs }` entries. Prints a JSON report.
+
+use std::error::Error;
+use std::fs;
+
+use oridion_provenance::{build_report, AccountDump, TransactionLog};
+
+fn parse_key(hex: &str) -> Result<[u8; 32], Box<dyn Error>> {
+    if hex.len() != 64 {
+        return Err("viewing key must be 32 bytes of hex".into());
+    }
+    let mut key = [0u8; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)?;
+    }
+    Ok(key)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut key = None;
+    let mut accounts = None;
+    let mut logs = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--key" => key = Some(parse_key(&value)?),
+            "--accounts" => accounts = Some(value),
+            "--logs" => logs = Some(value),
+            _ => return Err(format!("unknown flag {flag}").into()),
+        }
+    }
+    let key = key.ok_or("--key is required")?;
+    let accounts: Vec<AccountDump> = serde_json::from_str(&fs::read_to_string(accounts.ok_or("--accounts is required")?)?)?;
+    let transactions: Vec<TransactionLog> = match logs {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+        None => Vec::new(),
+    };
+
+    let report = build_report(&key, &accounts, &transactions)?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use base64::Engine;
+use oridion::account_pod::{DisclosureMeta, Pod, PodDisclosure, PodState};
+use oridion_provenance::{build_report, check_meta, open, public_key, seal, AccountData, AccountDump, TransactionLog, ENVELOPE_LEN};
+
+const VIEWER: [u8; 32] = [1u8; 32];
+const COMPLIANCE: [u8; 32] = [2u8; 32];
+
+fn dump<T: AccountSerialize>(key: Pubkey, account: &T) -> AccountDump {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    AccountDump {
+        pubkey: key.to_string(),
+        account: AccountData {
+            data: (base64::engine::general_purpose::STANDARD.encode(data), "base64".into()),
+        },
+    }
+}
+
+fn meta() -> DisclosureMeta {
+    DisclosureMeta {
+        creator: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        lamports: 1_000_000,
+        created_at: 1_700_000_000,
+    }
+}
+
+#[test]
+fn envelope_opens_only_for_its_recipient() {
+    let meta = meta();
+    let envelope = seal(&public_key(&VIEWER), [7u8; 32], &meta);
+    assert_eq!(envelope.len(), ENVELOPE_LEN);
+
+    assert_eq!(open(&VIEWER, &envelope), Some(meta));
+    assert_eq!(open(&COMPLIANCE, &envelope), None);
+}
+
+#[test]
+fn report_joins_disclosure_pod_and_logs() {
+    let meta = meta();
+    let pod_key = Pubkey::new_unique();
+    let pod = Pod {
+        state: PodState::Landed,
+        landed_at: meta.created_at + 600,
+        created_at: meta.created_at,
+        lamports: meta.lamports,
+        destination: meta.destination.to_bytes(),
+        source: Pod::source_hash(&meta.creator, &pod_key),
+        ..Pod::default()
+    };
+    let disclosure = PodDisclosure {
+        pod: pod_key,
+        viewer: seal(&public_key(&VIEWER), [8u8; 32], &meta),
+        compliance: seal(&public_key(&COMPLIANCE), [9u8; 32], &meta),
+        bump: 0,
+    };
+    let accounts = [dump(pod_key, &pod), dump(Pubkey::new_unique(), &disclosure)];
+    let logs = [
+        TransactionLog { signature: "a".into(), slot: 1, block_time: None, accounts: vec![pod_key.to_string()], logs: vec![] },
+        TransactionLog { signature: "b".into(), slot: 2, block_time: None, accounts: vec![], logs: vec![] },
+    ];
+
+    for key in [VIEWER, COMPLIANCE] {
+        let report = build_report(&key, &accounts, &logs).unwrap();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].destination, meta.destination.to_string());
+        assert_eq!(report[0].state.as_deref(), Some("Landed"));
+        assert_eq!(report[0].transactions.len(), 1);
+        assert!(report[0].verified);
+    }
+
+    assert!(build_report(&[3u8; 32], &accounts, &logs).unwrap().is_empty());
+}
+
+#[test]
+fn disclosures_that_disagree_with_the_pod_are_flagged() {
+    let meta = meta();
+    let pod_key = Pubkey::new_unique();
+    let honest = Pod {
+        created_at: meta.created_at,
+        lamports: meta.lamports,
+        destination: meta.destination.to_bytes(),
+        source: Pod::source_hash(&meta.creator, &pod_key),
+        ..Pod::default()
+    };
+    assert_eq!(check_meta(&meta, &pod_key, &honest), (vec![], true));
+
+    // A disclosure naming someone else's pod, or lying about it, does not match.
+    let (mismatches, _) = check_meta(&meta, &Pubkey::new_unique(), &honest);
+    assert_eq!(mismatches, ["creator"]);
+    let lied = DisclosureMeta { lamports: 1, destination: Pubkey::new_unique(), ..meta };
+    let (mismatches, _) = check_meta(&lied, &pod_key, &honest);
+    assert_eq!(mismatches, ["lamports", "destination"]);
+
+    // Sealed destinations cannot be checked without the reveal salt.
+    let sealed = Pod { sealed: true, destination: [7u8; 32], ..honest };
+    assert_eq!(check_meta(&meta, &pod_key, &sealed), (vec![], false));
+}